@@ -1,4 +1,4 @@
-use aoc_util::{Grid, DOWN, DOWN_LEFT, DOWN_RIGHT, RIGHT};
+use aoc_util::Grid;
 
 fn main() {
     let input = include_str!("../input/04.txt");
@@ -8,36 +8,13 @@ fn main() {
 
 fn part_1(input: &str) -> usize {
     let grid = Grid::from_bytes(input);
-    let mut count = 0;
-
-    for p in grid.points() {
-        count += [RIGHT, DOWN_RIGHT, DOWN, DOWN_LEFT]
-            .into_iter()
-            .map(|step| grid.get_range(p, step, 4).copied().collect())
-            .filter(|x: &Vec<u8>| matches!(&x[..], b"XMAS" | b"SAMX"))
-            .count();
-    }
-
-    count
+    grid.find_word(b"XMAS").len()
 }
 
-fn part_2(input: &str) -> u32 {
+fn part_2(input: &str) -> usize {
     let grid = Grid::from_bytes(input);
-    let mut count = 0;
-
-    for p in grid.points() {
-        let matching = [DOWN_LEFT, DOWN_RIGHT]
-            .into_iter()
-            .map(|step| grid.get_range(p - step, step, 3).copied().collect())
-            .filter(|x: &Vec<u8>| matches!(&x[..], b"MAS" | b"SAM"))
-            .count();
-
-        if matching == 2 {
-            count += 1;
-        }
-    }
-
-    count
+    let pattern = Grid::pattern_from_bytes("M.S\n.A.\nM.S", b'.');
+    grid.find_pattern_oriented(&pattern).len()
 }
 
 #[test]
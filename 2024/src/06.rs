@@ -13,15 +13,14 @@ fn guard_position(grid: &Grid<u8>) -> Vec2 {
 fn part_1(input: &str) -> usize {
     let grid = Grid::from_bytes(input);
     let start = guard_position(&grid);
-    let mut dir = Dir::from(grid[start]);
+    let mut dir = Dir::try_from(grid[start]).unwrap();
 
     let mut current = start;
-    let mut steps = grid.with_fill(false);
+    let mut steps = grid.with_bits::<1>();
     let mut count = 0;
 
     while grid.has(current) {
-        if !steps[current] {
-            steps[current] = true;
+        if steps.insert(current) {
             count += 1;
         }
         if grid.get(current.neighbor(dir)) == Some(&b'#') {
@@ -34,15 +33,14 @@ fn part_1(input: &str) -> usize {
 }
 
 fn is_loop(grid: &Grid<u8>, start: Vec2) -> bool {
-    let mut dir = Dir::from(grid[start]);
-    let mut steps = grid.with_fill([false; 4]);
+    let mut dir = Dir::try_from(grid[start]).unwrap();
+    let mut steps = grid.with_bits::<4>();
 
     let mut current = start;
     while grid.has(current) {
-        if steps[current][dir as usize] {
+        if !steps.insert((current, dir)) {
             return true;
         }
-        steps[current][dir as usize] = true;
 
         let next = current.neighbor(dir);
         if grid.get(next) == Some(&b'#') {
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use aoc_util::Grid;
+use aoc_util::{lattice_points, Grid};
 
 fn main() {
     let input = include_str!("../input/08.txt");
@@ -53,25 +53,22 @@ fn part_2(input: &str) -> u32 {
         }
     }
 
-    let mut antinodes = grid.with_fill(1);
+    let mut antinodes = grid.with_bits::<1>();
     let mut count = 0;
 
     for points in map.values() {
         for i in 0..points.len() {
             let a = points[i];
             for &b in points.iter().skip(i + 1) {
-                let dist = b - a;
-
-                let mut before = a;
-                while let Some(value) = antinodes.replace(before, 0) {
-                    before -= dist;
-                    count += value;
-                }
-
-                let mut after = b;
-                while let Some(value) = antinodes.replace(after, 0) {
-                    after += dist;
-                    count += value;
+                let step = (b - a).reduced();
+                let line = grid
+                    .ray(a, -step)
+                    .chain(lattice_points(a, b))
+                    .chain(grid.ray(b, step));
+                for p in line {
+                    if antinodes.insert(p) {
+                        count += 1;
+                    }
                 }
             }
         }
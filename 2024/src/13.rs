@@ -1,4 +1,4 @@
-use aoc_util::Parse;
+use aoc_util::{vec2, Parse, Vec2};
 
 fn main() {
     let input = include_str!("../input/13.txt");
@@ -6,17 +6,11 @@ fn main() {
     println!("Part 2: {}", part_2(input));
 }
 
-#[derive(Debug)]
-struct Vec2f {
-    x: f64,
-    y: f64,
-}
-
 #[derive(Debug)]
 struct Machine {
-    a: Vec2f,
-    b: Vec2f,
-    prize: Vec2f,
+    a: Vec2<f64>,
+    b: Vec2<f64>,
+    prize: Vec2<f64>,
 }
 
 impl Machine {
@@ -24,9 +18,9 @@ impl Machine {
         input.parse_regex(
             r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)",
             |[ax, ay, bx, by, px, py]| {
-                let button_a = Vec2f { x: ax, y: ay };
-                let button_b = Vec2f { x: bx, y: by };
-                let prize = Vec2f { x: px, y: py };
+                let button_a = vec2(ax, ay);
+                let button_b = vec2(bx, by);
+                let prize = vec2(px, py);
                 Self {
                     a: button_a,
                     b: button_b,
@@ -39,8 +33,8 @@ impl Machine {
 
 fn find_min_tokens(machine: Machine) -> usize {
     let Machine { a, b, prize } = machine;
-    let b_value = (prize.x * a.y - prize.y * a.x) / (b.x * a.y - b.y * a.x);
-    let a_value = (prize.x - b_value * b.x) / a.x;
+    let b_value = a.cross(prize) / a.cross(b);
+    let a_value = prize.cross(b) / a.cross(b);
     if a_value < 0.0 || b_value < 0.0 || a_value.fract() != 0.0 || b_value.fract() != 0.0 {
         return 0;
     }
@@ -58,8 +52,7 @@ fn part_1(input: &str) -> usize {
 fn part_2(input: &str) -> usize {
     let mut total = 0;
     for mut machine in Machine::parse(input) {
-        machine.prize.x += 10000000000000.0;
-        machine.prize.y += 10000000000000.0;
+        machine.prize += 10000000000000.0;
         total += find_min_tokens(machine);
     }
     total
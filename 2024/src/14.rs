@@ -17,11 +17,11 @@ fn parse_input(input: &str) -> Vec<(Vec2, Vec2)> {
 
 fn part_1(input: &str, width: isize, height: isize) -> usize {
     let mut robots = parse_input(input);
+    let size = vec2(width, height);
 
     for _ in 0..100 {
         for (pos, velocity) in &mut robots {
-            pos.x = (pos.x + velocity.x + width) % width;
-            pos.y = (pos.y + velocity.y + height) % height;
+            *pos = (*pos + *velocity).rem_euclid(size);
         }
     }
 
@@ -46,17 +46,17 @@ fn part_1(input: &str, width: isize, height: isize) -> usize {
 
 fn part_2(input: &str, width: isize, height: isize) {
     let mut robots = parse_input(input);
+    let size = vec2(width, height);
     let mut file = BufWriter::new(File::create("output.txt").unwrap());
     for i in 0..10000 {
-        let mut grid = Grid::new(width as usize, height as usize, ' ');
+        let mut grid = Grid::new(width as usize, height as usize, false);
         for (pos, velocity) in &mut robots {
-            pos.x = (pos.x + velocity.x + width) % width;
-            pos.y = (pos.y + velocity.y + height) % height;
-            grid[*pos] = '#';
+            *pos = (*pos + *velocity).rem_euclid(size);
+            grid[*pos] = true;
         }
 
         writeln!(&mut file, "Iteration: {}\n", i + 1).unwrap();
-        writeln!(&mut file, "{grid}").unwrap();
+        writeln!(&mut file, "{}", grid.render()).unwrap();
     }
 }
 
@@ -9,10 +9,7 @@ fn main() {
 fn parse_input(input: &str) -> (Grid<u8>, Vec<Dir>) {
     let (grid, directions) = input.split_once("\n\n").unwrap();
     let grid = Grid::from_bytes(grid);
-    let directions = directions
-        .lines()
-        .flat_map(|line| line.bytes().map(Dir::from))
-        .collect();
+    let directions = Dir::parse_moves(directions).unwrap();
     (grid, directions)
 }
 
@@ -1,21 +1,6 @@
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::HashSet;
 
-use aoc_util::{Dir, Grid};
-
-#[derive(PartialEq, Eq)]
-struct Sort<T>(usize, T);
-
-impl<T: Eq> PartialOrd for Sort<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl<T: Eq> Ord for Sort<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.0.cmp(&self.0)
-    }
-}
+use aoc_util::{astar, dijkstra_all, Dir, Grid, Vec2};
 
 fn main() {
     let input = include_str!("../input/16.txt");
@@ -23,71 +8,51 @@ fn main() {
     println!("Part 2: {}", part_2(input));
 }
 
+/// The states reachable from a reindeer's state, with the cost of each move.
+fn moves(grid: &Grid<u8>, (pos, dir): (Vec2, Dir)) -> Vec<((Vec2, Dir), usize)> {
+    let mut out = vec![
+        ((pos, dir.turn_left()), 1000),
+        ((pos, dir.turn_right()), 1000),
+    ];
+    let next = pos.neighbor(dir);
+    if grid[next] != b'#' {
+        out.push(((next, dir), 1));
+    }
+    out
+}
+
 fn part_1(input: &str) -> usize {
     let grid = Grid::from_bytes(input);
     let start = grid.find(|&c| c == b'S');
-    let mut visited = grid.with_fill([false; 4]);
-
-    let mut min_dist = usize::MAX;
-    let mut queue = BinaryHeap::new();
-    queue.push(Sort(0, (start, Dir::Right)));
-
-    while let Some(Sort(dist, (pos, dir))) = queue.pop() {
-        if grid[pos] == b'E' {
-            min_dist = min_dist.min(dist);
-            continue;
-        }
-        if visited[pos][dir as usize] {
-            continue;
-        }
-        visited[pos][dir as usize] = true;
-        let next = pos.neighbor(dir);
-        if grid[next] != b'#' {
-            queue.push(Sort(dist + 1, (next, dir)));
-        }
-        queue.push(Sort(dist + 1000, (pos, dir.turn_left())));
-        queue.push(Sort(dist + 1000, (pos, dir.turn_right())));
-    }
-
-    min_dist
+    let end = grid.find(|&c| c == b'E');
+
+    let (dist, _) = astar(
+        (start, Dir::Right),
+        |state| moves(&grid, state),
+        |(pos, _)| pos.manhattan_dist(end) as usize,
+        |(pos, _)| pos == end,
+    )
+    .unwrap();
+    dist
 }
 
 fn part_2(input: &str) -> usize {
     let grid = Grid::from_bytes(input);
     let start = grid.find(|&c| c == b'S');
-
-    let mut min_dist = usize::MAX;
-    let mut visited = grid.with_fill([usize::MAX; 4]);
-    let mut path_map = HashMap::new();
-
-    let mut queue = BinaryHeap::new();
-    queue.push(Sort(0, (start, Dir::Right, vec![start])));
-
-    while let Some(Sort(dist, (pos, dir, path))) = queue.pop() {
-        if visited[pos][dir as usize] < dist {
-            continue;
-        }
-        visited[pos][dir as usize] = dist;
-        if grid[pos] == b'E' {
-            min_dist = min_dist.min(dist);
-            path_map
-                .entry(dist)
-                .or_insert_with(HashSet::new)
-                .extend(path);
-            continue;
-        }
-
-        let next = pos.neighbor(dir);
-        if grid[next] != b'#' {
-            let mut path = path.clone();
-            path.push(next);
-            queue.push(Sort(dist + 1, (next, dir, path)));
-        }
-        queue.push(Sort(dist + 1000, (pos, dir.turn_left(), path.clone())));
-        queue.push(Sort(dist + 1000, (pos, dir.turn_right(), path)));
-    }
-
-    path_map[&min_dist].len()
+    let end = grid.find(|&c| c == b'E');
+
+    let paths = dijkstra_all((start, Dir::Right), |state| moves(&grid, state));
+    let (_, best) = paths.nearest(|(pos, _)| pos == end).unwrap();
+    let ends = Dir::all()
+        .map(|dir| (end, dir))
+        .filter(|&state| paths.distance(state) == Some(best));
+
+    paths
+        .states_on_paths(ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len()
 }
 
 #[test]
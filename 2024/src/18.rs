@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, i32};
 
-use aoc_util::{vec2, Grid, Parse, Vec2};
+use aoc_util::{vec2, Grid, Vec2};
 
 fn main() {
     let input = include_str!("../input/18.txt");
@@ -9,13 +9,7 @@ fn main() {
 }
 
 fn parse_input(input: &str) -> Vec<Vec2> {
-    input
-        .lines()
-        .map(|s| {
-            let (a, b) = s.pair(",");
-            vec2(a, b)
-        })
-        .collect()
+    input.lines().map(|s| s.parse().unwrap()).collect()
 }
 
 fn calculate_shortest_path(grid: &Grid<char>, start: Vec2, end: Vec2) -> i32 {
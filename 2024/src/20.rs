@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, usize};
 
-use aoc_util::{Grid, Vec2};
+use aoc_util::{Grid, Neighborhood, Vec2};
 
 fn main() {
     let input = include_str!("../input/20.txt");
@@ -37,17 +37,15 @@ fn solve(input: &str, min_save: usize, allowed_skips: usize) -> usize {
     let (dist, path) = distances(&grid, start);
     let (rev_dist, _) = distances(&grid, end);
 
+    let cheats = Neighborhood::von_neumann(allowed_skips);
     let mut count = 0;
     for p in path {
-        for q in grid.points() {
+        for q in grid.neighbors_with(p, &cheats) {
             if rev_dist[q] == usize::MAX {
                 continue;
             }
 
             let distance = p.manhattan_dist(q) as usize;
-            if distance > allowed_skips {
-                continue;
-            }
 
             if dist[p] + distance + rev_dist[q] <= dist[end] - min_save {
                 count += 1;
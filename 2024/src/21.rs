@@ -47,7 +47,7 @@ fn get_complexity_rec(
         let mut a = b'A';
         for &&d in p.iter() {
             steps += get_complexity_rec(grids, id + 1, a, d, memo);
-            pos = pos.neighbor(Dir::from(d));
+            pos = pos.neighbor(Dir::try_from(d).unwrap());
             if g[pos] == b' ' {
                 continue 'outer;
             }
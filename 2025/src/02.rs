@@ -1,72 +1,54 @@
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
+use aoc_util::IntervalSet;
+
 fn main() {
     let input = include_str!("../input/02.txt");
     println!("Part 1: {}", part_1(input));
     println!("Part 2: {}", part_2(input));
 }
 
-fn is_valid_part_1(n: i64) -> bool {
-    let s = n.to_string();
-    if s.len() % 2 == 1 {
-        return true;
+/// All numbers in the range made of a block of digits repeated a number of times accepted by `repeats`.
+fn repeated_numbers(range: RangeInclusive<i64>, repeats: impl Fn(u32) -> bool) -> BTreeSet<i64> {
+    let (lo, hi) = ((*range.start()).max(1), *range.end());
+    let mut out = BTreeSet::new();
+    if lo > hi {
+        return out;
     }
-    s[..s.len() / 2] != s[s.len() / 2..]
-}
-
-fn is_valid_part_2(n: i64) -> bool {
-    let s = n.to_string();
-    let s = s.as_bytes();
-    for len in 1..=(s.len() / 2) {
-        if s.len() % len != 0 {
-            continue;
-        }
-        let mut chunks = s.chunks_exact(len);
-        if let Some(first) = chunks.next() {
-            if chunks.all(|chunk| chunk == first) {
-                return false;
+    for digits in lo.ilog10() + 1..=hi.ilog10() + 1 {
+        for len in (1..=digits / 2).filter(|&len| digits % len == 0) {
+            let times = digits / len;
+            if !repeats(times) {
+                continue;
             }
+            // Repeating the block is multiplying it by 10..010..01, with `len - 1` zeros in each gap.
+            let Some(multiplier) = (1..times).try_fold(1i64, |m, _| {
+                m.checked_mul(10i64.checked_pow(len)?)?.checked_add(1)
+            }) else {
+                continue;
+            };
+            let first = 10i64.pow(len - 1).max((lo - 1) / multiplier + 1);
+            let last = (10i64.pow(len) - 1).min(hi / multiplier);
+            out.extend((first..=last).map(|block| block * multiplier));
         }
     }
-    true
+    out
 }
 
-fn part_1(input: &str) -> i64 {
-    let ranges = input
-        .split(',')
-        .map(|range| {
-            let (start, end) = range.split_once('-').unwrap();
-            (start.parse::<i64>().unwrap(), end.parse::<i64>().unwrap())
-        })
-        .collect::<Vec<_>>();
+fn sum_invalid(input: &str, repeats: impl Fn(u32) -> bool) -> i64 {
+    let ranges: IntervalSet<i64> = input.parse().unwrap();
+    ranges
+        .intervals()
+        .flat_map(|range| repeated_numbers(range, &repeats))
+        .sum()
+}
 
-    let mut out = 0;
-    for (start, end) in ranges {
-        for i in start..=end {
-            if !is_valid_part_1(i) {
-                out += i;
-            }
-        }
-    }
-    out
+fn part_1(input: &str) -> i64 {
+    sum_invalid(input, |times| times == 2)
 }
 
 fn part_2(input: &str) -> i64 {
-    let ranges = input
-        .split(',')
-        .map(|range| {
-            let (start, end) = range.split_once('-').unwrap();
-            (start.parse::<i64>().unwrap(), end.parse::<i64>().unwrap())
-        })
-        .collect::<Vec<_>>();
-
-    let mut out = 0;
-    for (start, end) in ranges {
-        for i in start..=end {
-            if !is_valid_part_2(i) {
-                out += i;
-            }
-        }
-    }
-    out
+    sum_invalid(input, |_| true)
 }
 
 #[test]
@@ -75,6 +57,28 @@ fn test_part_1() {
     assert_eq!(part_1(data), 1227775554);
 }
 
+#[test]
+fn test_large_values() {
+    assert_eq!(
+        repeated_numbers(i64::MAX - 10..=i64::MAX, |_| true),
+        BTreeSet::new()
+    );
+    assert_eq!(
+        repeated_numbers(999_999_999_999_999_990..=i64::MAX, |_| true),
+        BTreeSet::from_iter(
+            std::iter::once(999_999_999_999_999_999)
+                .chain((1..=8).map(|d| d * 1_111_111_111_111_111_111))
+        )
+    );
+    assert_eq!(
+        repeated_numbers(
+            123_456_789_123_456_789..=123_456_790_000_000_000,
+            |times| times == 2
+        ),
+        BTreeSet::from([123_456_789_123_456_789])
+    );
+}
+
 #[test]
 fn test_part_2() {
     let data = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
@@ -1,4 +1,4 @@
-use aoc_util::Grid;
+use aoc_util::{Automaton, Grid, Stop};
 
 fn main() {
     let input = include_str!("../input/04.txt");
@@ -18,24 +18,15 @@ fn part_1(input: &str) -> usize {
 }
 
 fn part_2(input: &str) -> usize {
-    let mut grid = Grid::from_bytes(input);
-    let mut count = 0;
-    loop {
-        let mut changed = false;
-        let mut new_grid = grid.clone();
-        for p in grid.points() {
-            if grid[p] == b'@' && grid.neighbors_all(p).filter(|n| grid[*n] == b'@').count() < 4 {
-                new_grid[p] = b'.';
-                count += 1;
-                changed = true;
-            }
-        }
-        if !changed {
-            break;
+    let mut automaton = Automaton::new(Grid::from_bytes(input)).worklist();
+    let outcome = automaton.run(Stop::Fixpoint, |&cell, neighbors| {
+        if cell == b'@' && neighbors.filter(|&&n| n == b'@').count() < 4 {
+            b'.'
+        } else {
+            cell
         }
-        grid = new_grid;
-    }
-    count
+    });
+    outcome.changes
 }
 
 #[test]
@@ -1,66 +1,26 @@
+use aoc_util::IntervalSet;
+
 fn main() {
     let input = include_str!("../input/05.txt");
     println!("Part 1: {}", part_1(input));
     println!("Part 2: {}", part_2(input));
 }
 
-struct Range {
-    start: usize,
-    end: usize,
-}
-
-fn parse_ranges(input: &str) -> Vec<Range> {
-    input
-        .lines()
-        .map(|line| line.split_once("-").unwrap())
-        .map(|(start, end)| Range {
-            start: start.parse().unwrap(),
-            end: end.parse().unwrap(),
-        })
-        .collect::<Vec<_>>()
-}
-
 fn part_1(input: &str) -> usize {
     let (fresh_ranges, ingredients) = input.split_once("\n\n").unwrap();
-    let fresh_ranges = parse_ranges(fresh_ranges);
+    let fresh_ranges: IntervalSet<usize> = fresh_ranges.parse().unwrap();
 
-    let ingredients = ingredients
+    ingredients
         .lines()
-        .map(|line| line.parse::<usize>().unwrap());
-    let mut out = 0;
-
-    for ingredient in ingredients {
-        if fresh_ranges
-            .iter()
-            .any(|range| ingredient >= range.start && ingredient <= range.end)
-        {
-            out += 1;
-        }
-    }
-    out
+        .map(|line| line.parse::<usize>().unwrap())
+        .filter(|&ingredient| fresh_ranges.contains(ingredient))
+        .count()
 }
 
 fn part_2(input: &str) -> usize {
     let (ranges, _) = input.split_once("\n\n").unwrap();
-    let mut ranges = parse_ranges(ranges);
-    ranges.sort_by_key(|range| range.start);
-
-    let mut new_ranges: Vec<Range> = vec![];
-    for range in ranges {
-        if let Some(last) = new_ranges.last_mut()
-            && range.start <= last.end
-        {
-            last.end = last.end.max(range.end);
-        } else {
-            new_ranges.push(range);
-        }
-    }
-
-    let mut out = 0;
-    for range in new_ranges {
-        out += range.end - range.start + 1;
-    }
-    out
+    let ranges: IntervalSet<usize> = ranges.parse().unwrap();
+    ranges.count()
 }
 
 #[test]
@@ -1,4 +1,4 @@
-use aoc_util::Grid;
+use aoc_util::{Grid, Parse};
 
 fn main() {
     let input = include_str!("../input/06.txt");
@@ -24,50 +24,21 @@ fn part_1(input: &str) -> i64 {
 }
 
 fn part_2(input: &str) -> i64 {
-    let grid = Grid::from_bytes(input);
+    let (input, operations) = input.rsplit_once("\n").unwrap();
+    let operations = operations.as_bytes();
 
     let mut output = 0;
-    let mut operator = b'+';
-    let mut numbers = Vec::<String>::new();
-
-    for col in grid.cols() {
-        let mut number = String::new();
-        for &v in col {
-            match v {
-                b'+' | b'*' => operator = v,
-                v => number.push(v as char),
-            }
-        }
-
-        if number.trim().is_empty() {
-            output += eval(&numbers, operator);
-            numbers.clear();
+    for group in input.column_groups() {
+        let numbers = group.col_numbers::<i64>();
+        if operations[group.start()] == b'+' {
+            output += numbers.iter().sum::<i64>();
         } else {
-            numbers.push(number);
+            output += numbers.iter().product::<i64>();
         }
     }
-
-    if !numbers.is_empty() {
-        output += eval(&numbers, operator);
-    }
-
     output
 }
 
-fn eval(numbers: &[String], operator: u8) -> i64 {
-    if operator == b'+' {
-        numbers
-            .iter()
-            .map(|v| v.trim().parse::<i64>().unwrap())
-            .sum::<i64>()
-    } else {
-        numbers
-            .iter()
-            .map(|v| v.trim().parse::<i64>().unwrap())
-            .product::<i64>()
-    }
-}
-
 #[test]
 fn test_part_1() {
     let data = r"123 328  51 64 
@@ -10,8 +10,8 @@ fn part_1(input: &str) -> i32 {
     let grid = Grid::from_bytes(input);
     let start = grid.find(|c| *c == b'S');
     let mut splits = 0;
-    let mut has_beam = grid.with_fill(false);
-    has_beam[start] = true;
+    let mut has_beam = grid.with_bits::<1>();
+    has_beam.insert(start);
 
     for pos in grid.points() {
         if !has_beam[pos] {
@@ -19,10 +19,12 @@ fn part_1(input: &str) -> i32 {
         }
         let next = pos.neighbor(Dir::Down);
         match grid.get(next) {
-            Some(b'.') => has_beam[next] = true,
+            Some(b'.') => {
+                has_beam.insert(next);
+            }
             Some(b'^') => {
-                has_beam[next.neighbor(Dir::Left)] = true;
-                has_beam[next.neighbor(Dir::Right)] = true;
+                has_beam.insert(next.neighbor(Dir::Left));
+                has_beam.insert(next.neighbor(Dir::Right));
                 splits += 1;
             }
             _ => (),
@@ -9,13 +9,13 @@ fn main() {
 }
 
 fn part_1(input: &str, circuits: usize) -> usize {
-    let junction_boxes: Vec<_> = input.lines().map(|line| Vec3::parse_csv(line)).collect();
+    let junction_boxes: Vec<Vec3> = input.lines().map(|line| line.parse().unwrap()).collect();
 
     let mut pairs = Vec::new();
     for i in 0..junction_boxes.len() {
         for j in i + 1..junction_boxes.len() {
             if i != j {
-                let dist = junction_boxes[i].euclidean_dist(junction_boxes[j]);
+                let dist = junction_boxes[i].euclidean_dist_sq(junction_boxes[j]);
                 pairs.push((dist, (i, j)));
             }
         }
@@ -37,13 +37,13 @@ fn part_1(input: &str, circuits: usize) -> usize {
 }
 
 fn part_2(input: &str) -> isize {
-    let junction_boxes: Vec<Vec3> = input.lines().map(|line| Vec3::parse_csv(line)).collect();
+    let junction_boxes: Vec<Vec3> = input.lines().map(|line| line.parse().unwrap()).collect();
 
     let mut pairs = Vec::new();
     for i in 0..junction_boxes.len() {
         for j in i + 1..junction_boxes.len() {
             if i != j {
-                let dist = junction_boxes[i].euclidean_dist(junction_boxes[j]);
+                let dist = junction_boxes[i].euclidean_dist_sq(junction_boxes[j]);
                 pairs.push((dist, (i, j)));
             }
         }
@@ -1,6 +1,4 @@
-use std::collections::VecDeque;
-
-use aoc_util::{Grid, Parse, Vec2, vec2};
+use aoc_util::{Polygon, Rect, Vec2};
 
 fn main() {
     let input = include_str!("../input/09.txt");
@@ -9,17 +7,11 @@ fn main() {
 }
 
 fn parse(input: &str) -> Vec<Vec2> {
-    input
-        .lines()
-        .map(|l| {
-            let (x, y) = l.pair(",");
-            vec2(x, y)
-        })
-        .collect()
+    input.lines().map(|l| l.parse().unwrap()).collect()
 }
 
 fn area(a: Vec2, b: Vec2) -> isize {
-    ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
+    Rect::new(a, b).area()
 }
 
 fn part_1(input: &str) -> isize {
@@ -35,59 +27,13 @@ fn part_1(input: &str) -> isize {
 
 fn part_2(input: &str) -> isize {
     let points = parse(input);
-
-    let mut xs: Vec<isize> = points.iter().map(|p| p.x).collect();
-    let mut ys: Vec<isize> = points.iter().map(|p| p.y).collect();
-    xs.sort();
-    xs.dedup();
-    ys.sort();
-    ys.dedup();
-
-    // Insert gap around every point and compress the point space
-    let points_with_gap: Vec<Vec2> = points
-        .iter()
-        .map(|p| {
-            vec2(
-                2 * xs.binary_search(&p.x).unwrap() as isize + 1,
-                2 * ys.binary_search(&p.y).unwrap() as isize + 1,
-            )
-        })
-        .collect();
-
-    let mut grid = Grid::new(2 * xs.len() + 1, 2 * ys.len() + 1, false);
-
-    for i in 0..points_with_gap.len() {
-        let a = points_with_gap[i];
-        let b = points_with_gap[(i + 1) % points_with_gap.len()];
-        for p in Vec2::rect_points(a, b) {
-            grid[p] = true;
-        }
-    }
-
-    // Flood fill exterior from (0,0)
-    // Guaranteed to be outside because of gaps.
-    let mut queue = VecDeque::from([vec2(0, 0)]);
-    let mut exterior = grid.with_fill(false);
-    exterior[vec2(0, 0)] = true;
-    while let Some(p) = queue.pop_front() {
-        for n in grid.neighbors(p) {
-            if !grid[n] && !exterior[n] {
-                exterior[n] = true;
-                queue.push_back(n);
-            }
-        }
-    }
+    let polygon = Polygon::new(points.clone());
 
     let mut max = 0;
     for i in 0..points.len() {
         for j in i + 1..points.len() {
             let rect_area = area(points[i], points[j]);
-            if rect_area <= max {
-                continue;
-            }
-            let (a, b) = (points_with_gap[i], points_with_gap[j]);
-            let valid = Vec2::rect_points(a, b).all(|p| !exterior[p]);
-            if valid {
+            if rect_area > max && polygon.contains_rect(points[i], points[j]) {
                 max = rect_area;
             }
         }
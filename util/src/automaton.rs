@@ -0,0 +1,313 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+    mem,
+};
+
+use crate::{Grid, Neighborhood, Vec2, vec2};
+
+/// How the new cell values are written back to the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Update {
+    /// Every cell sees the grid as it was at the start of the step.
+    Sync,
+    /// Cells are updated in row-major order and later cells see the earlier changes.
+    InPlace,
+}
+
+/// When to stop running the automaton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// Stop once a step changes nothing.
+    Fixpoint,
+    /// Stop after the given number of steps.
+    Steps(usize),
+}
+
+/// Summary of a call to [`Automaton::run`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    /// Number of steps that changed the grid.
+    pub steps: usize,
+    /// Total number of cell changes over all steps.
+    pub changes: usize,
+    /// For [`Automaton::run_until_repeat`], the step at which the repeated state was first seen
+    /// and the cycle length.
+    pub cycle: Option<(usize, usize)>,
+}
+
+/// The in-bounds neighbours of a cell, handed to the automaton rule.
+pub struct Neighbors<'a, T> {
+    grid: &'a Grid<T>,
+    point: Vec2,
+    offsets: std::slice::Iter<'a, Vec2>,
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &offset in self.offsets.by_ref() {
+            if let Some(value) = self.grid.get(self.point + offset) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+/// A cellular automaton that repeatedly applies a rule to every cell of a grid.
+///
+/// The rule receives the current value of a cell and its neighbours, and returns the new value.
+pub struct Automaton<T> {
+    grid: Grid<T>,
+    update: Update,
+    neighborhood: Neighborhood,
+    worklist: bool,
+    pending: Vec<Vec2>,
+    queued: Grid<bool>,
+}
+
+impl<T> Automaton<T> {
+    /// Create a synchronous automaton over the 8 surrounding cells.
+    pub fn new(grid: Grid<T>) -> Self {
+        let queued = grid.with_fill(false);
+        Self {
+            grid,
+            update: Update::Sync,
+            neighborhood: Neighborhood::moore(1),
+            worklist: false,
+            pending: vec![],
+            queued,
+        }
+    }
+
+    /// Set how new values are written back to the grid.
+    pub fn update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Only pass the 4 orthogonal neighbours to the rule.
+    pub fn orthogonal(self) -> Self {
+        self.neighborhood(Neighborhood::von_neumann(1))
+    }
+
+    /// Set which cells are passed to the rule as neighbours.
+    pub fn neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// Only re-examine cells next to the ones that changed in the previous step.
+    ///
+    /// This is only correct if the rule depends on nothing but the cell and its neighbours.
+    pub fn worklist(mut self) -> Self {
+        self.worklist = true;
+        self.pending = self.grid.points().collect();
+        self.queued = self.grid.with_fill(true);
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Apply the rule once, returning the number of cells that changed.
+    pub fn step<F>(&mut self, mut rule: F) -> usize
+    where
+        T: PartialEq,
+        F: FnMut(&T, Neighbors<'_, T>) -> T,
+    {
+        let points = if self.worklist {
+            let mut points = mem::take(&mut self.pending);
+            points.sort_unstable_by_key(|p| (p.y, p.x));
+            for &p in &points {
+                self.queued[p] = false;
+            }
+            points
+        } else {
+            self.grid.points().collect()
+        };
+
+        let mut changed = vec![];
+        match self.update {
+            Update::Sync => {
+                let mut updates = vec![];
+                for p in points {
+                    let value = rule(&self.grid[p], self.neighbors(p));
+                    if value != self.grid[p] {
+                        updates.push((p, value));
+                    }
+                }
+                for (p, value) in updates {
+                    self.grid[p] = value;
+                    changed.push(p);
+                }
+            }
+            Update::InPlace => {
+                // With a worklist, readers that come later in row-major order must see a change
+                // within the same step, as they would in a full scan.
+                let mut points: BTreeSet<(isize, isize)> =
+                    points.into_iter().map(|p| (p.y, p.x)).collect();
+                while let Some((y, x)) = points.pop_first() {
+                    let p = vec2(x, y);
+                    let value = rule(&self.grid[p], self.neighbors(p));
+                    if value != self.grid[p] {
+                        self.grid[p] = value;
+                        changed.push(p);
+                        if self.worklist {
+                            for r in readers(&self.neighborhood, p) {
+                                if (r.y, r.x) > (y, x) && self.grid.get(r).is_some() {
+                                    points.insert((r.y, r.x));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.worklist {
+            for &p in &changed {
+                for n in std::iter::once(p).chain(readers(&self.neighborhood, p)) {
+                    if self.queued.replace(n, true) == Some(false) {
+                        self.pending.push(n);
+                    }
+                }
+            }
+        }
+        changed.len()
+    }
+
+    /// Run the automaton until the given stop condition is reached.
+    pub fn run<F>(&mut self, stop: Stop, mut rule: F) -> Outcome
+    where
+        T: PartialEq,
+        F: FnMut(&T, Neighbors<'_, T>) -> T,
+    {
+        let mut outcome = Outcome {
+            steps: 0,
+            changes: 0,
+            cycle: None,
+        };
+        while stop != Stop::Steps(outcome.steps) {
+            let changes = self.step(&mut rule);
+            if changes == 0 {
+                break;
+            }
+            outcome.steps += 1;
+            outcome.changes += changes;
+        }
+        outcome
+    }
+
+    /// Run the automaton until the grid returns to a state it has been in before.
+    ///
+    /// A fixpoint counts as a cycle of length 1.
+    pub fn run_until_repeat<F>(&mut self, mut rule: F) -> Outcome
+    where
+        T: Clone + Eq + Hash,
+        F: FnMut(&T, Neighbors<'_, T>) -> T,
+    {
+        let mut outcome = Outcome {
+            steps: 0,
+            changes: 0,
+            cycle: None,
+        };
+        let mut seen = HashMap::from([(self.grid.clone(), 0)]);
+        loop {
+            let changes = self.step(&mut rule);
+            if changes == 0 {
+                outcome.cycle = Some((outcome.steps, 1));
+                break;
+            }
+            outcome.steps += 1;
+            outcome.changes += changes;
+
+            if let Some(&start) = seen.get(&self.grid) {
+                outcome.cycle = Some((start, outcome.steps - start));
+                break;
+            }
+            seen.insert(self.grid.clone(), outcome.steps);
+        }
+        outcome
+    }
+
+    fn neighbors(&self, point: Vec2) -> Neighbors<'_, T> {
+        Neighbors {
+            grid: &self.grid,
+            point,
+            offsets: self.neighborhood.offsets().iter(),
+        }
+    }
+}
+
+/// The cells that have `point` as a neighbour. A cell reads `point` through offset `o` if it
+/// sits at `point - o`, which only matches `point + o` for symmetric neighbourhoods.
+fn readers(neighborhood: &Neighborhood, point: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+    neighborhood.offsets().iter().map(move |&o| point - o)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RIGHT;
+
+    #[test]
+    fn worklist_matches_full_scan_for_asymmetric_neighborhood() {
+        let grid = Grid::from_bytes("....#");
+        let rule = |&c: &u8, mut n: Neighbors<'_, u8>| {
+            if n.next() == Some(&b'#') { b'#' } else { c }
+        };
+
+        let mut full = Automaton::new(grid.clone()).neighborhood(Neighborhood::custom([RIGHT]));
+        let full_outcome = full.run(Stop::Fixpoint, rule);
+        let mut worklist = Automaton::new(grid)
+            .neighborhood(Neighborhood::custom([RIGHT]))
+            .worklist();
+        let worklist_outcome = worklist.run(Stop::Fixpoint, rule);
+
+        assert_eq!(full.grid(), &Grid::from_bytes("#####"));
+        assert_eq!(worklist.grid(), full.grid());
+        assert_eq!(worklist_outcome, full_outcome);
+        assert_eq!(full_outcome.steps, 4);
+    }
+
+    #[test]
+    fn worklist_matches_full_scan_in_place() {
+        let grid = Grid::from_vec(vec![2u8, 2, 0, 0], 4);
+        let rule = |&c: &u8, n: Neighbors<'_, u8>| {
+            let k = n.sum::<u8>();
+            if k % 3 == 0 { c } else { (c + k) % 3 }
+        };
+
+        let mut full = Automaton::new(grid.clone())
+            .orthogonal()
+            .update(Update::InPlace);
+        full.run(Stop::Steps(2), rule);
+        let mut worklist = Automaton::new(grid)
+            .orthogonal()
+            .update(Update::InPlace)
+            .worklist();
+        worklist.run(Stop::Steps(2), rule);
+
+        assert_eq!(full.grid(), &Grid::from_vec(vec![1, 1, 1, 1], 4));
+        assert_eq!(worklist.grid(), full.grid());
+    }
+
+    #[test]
+    fn run_works_on_cells_that_cannot_be_hashed() {
+        let grid = Grid::from_vec(vec![0.0, 0.0, 8.0], 3);
+        let mut automaton = Automaton::new(grid).orthogonal();
+        let outcome = automaton.run(Stop::Steps(2), |&c: &f64, n| {
+            n.fold(c, |max: f64, &x| max.max(x / 2.0))
+        });
+        assert_eq!(outcome.steps, 2);
+        assert_eq!(automaton.grid(), &Grid::from_vec(vec![2.0, 4.0, 8.0], 3));
+    }
+}
@@ -0,0 +1,218 @@
+use std::ops::Index;
+
+use crate::{Dir, Grid, Vec2};
+
+/// A key that selects one bit of a [`BitGrid`] with `N` bits per cell.
+pub trait BitKey<const N: usize> {
+    /// The cell and the bit within the cell.
+    fn split(self) -> (Vec2, usize);
+}
+
+impl BitKey<1> for Vec2 {
+    fn split(self) -> (Vec2, usize) {
+        (self, 0)
+    }
+}
+
+impl BitKey<4> for (Vec2, Dir) {
+    fn split(self) -> (Vec2, usize) {
+        (self.0, self.1 as usize)
+    }
+}
+
+impl<const N: usize> BitKey<N> for (Vec2, usize) {
+    fn split(self) -> (Vec2, usize) {
+        assert!(self.1 < N, "Bit index out of range");
+        self
+    }
+}
+
+/// A grid of `N` boolean flags per cell, packed into `u64` words.
+///
+/// `BitGrid` is a visited map over a [`Grid`], and `BitGrid<4>` keyed by `(Vec2, Dir)` tracks
+/// per-direction state.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid<const N: usize = 1> {
+    words: Vec<u64>,
+    cols: usize,
+    rows: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create an empty bit grid with the same dimensions as this one.
+    pub fn with_bits<const N: usize>(&self) -> BitGrid<N> {
+        BitGrid::new(self.width(), self.height())
+    }
+}
+
+impl<const N: usize> BitGrid<N> {
+    /// Create a bit grid with the given dimensions and every bit cleared.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            words: vec![0; (width * height * N).div_ceil(64)],
+            cols: width,
+            rows: height,
+        }
+    }
+
+    /// Number of rows in the grid.
+    pub const fn height(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns in the grid.
+    pub const fn width(&self) -> usize {
+        self.cols
+    }
+
+    /// Iterate over the points in the grid.
+    pub fn points(&self) -> impl Iterator<Item = Vec2> + use<N> {
+        let rows = self.height() as isize;
+        let cols = self.width() as isize;
+        (0..rows).flat_map(move |y| (0..cols).map(move |x| Vec2 { x, y }))
+    }
+
+    /// Check if the given point is within the bounds of the grid.
+    pub fn has(&self, point: Vec2) -> bool {
+        self.to_index(point).is_some()
+    }
+
+    /// Get the bit for the given key, if the point is in bounds.
+    pub fn get(&self, key: impl BitKey<N>) -> Option<bool> {
+        let i = self.bit_index(key)?;
+        Some(self.words[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    /// Check if the bit for the given key is set. Out of bounds points are never set.
+    pub fn contains(&self, key: impl BitKey<N>) -> bool {
+        self.get(key) == Some(true)
+    }
+
+    /// Check if any of the bits of the given cell are set.
+    pub fn any(&self, point: Vec2) -> bool {
+        (0..N).any(|bit| self.contains((point, bit)))
+    }
+
+    /// Set the bit for the given key, returning the old value.
+    ///
+    /// Panics if the point is out of bounds.
+    pub fn set(&mut self, key: impl BitKey<N>, value: bool) -> bool {
+        let i = self.bit_index(key).expect("Point out of bounds");
+        let mask = 1 << (i % 64);
+        let word = &mut self.words[i / 64];
+        let old = *word & mask != 0;
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+        old
+    }
+
+    /// Set the bit for the given key, returning true if it was not already set.
+    pub fn insert(&mut self, key: impl BitKey<N>) -> bool {
+        !self.set(key, true)
+    }
+
+    /// Clear the bit for the given key, returning true if it was set.
+    pub fn remove(&mut self, key: impl BitKey<N>) -> bool {
+        self.set(key, false)
+    }
+
+    /// Clear every bit.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Number of bits set.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Number of cells with at least one bit set.
+    pub fn count_cells(&self) -> usize {
+        if N == 1 {
+            self.count()
+        } else {
+            self.cells().count()
+        }
+    }
+
+    /// Iterate over the cells with at least one bit set.
+    pub fn cells(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.points().filter(|&p| self.any(p))
+    }
+
+    /// Iterate over every set bit as a cell and the bit within the cell.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, usize)> + '_ {
+        self.words.iter().enumerate().flat_map(move |(w, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let i = w * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
+                let cell = i / N;
+                let point = Vec2 {
+                    x: (cell % self.cols) as isize,
+                    y: (cell / self.cols) as isize,
+                };
+                Some((point, i % N))
+            })
+        })
+    }
+
+    /// Set every bit that is set in `other`.
+    pub fn union_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a | b);
+    }
+
+    /// Clear every bit that is not set in `other`.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & b);
+    }
+
+    /// Clear every bit that is set in `other`.
+    pub fn difference_with(&mut self, other: &Self) {
+        self.zip_with(other, |a, b| a & !b);
+    }
+
+    fn zip_with(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!((self.cols, self.rows), (other.cols, other.rows));
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+
+    fn bit_index(&self, key: impl BitKey<N>) -> Option<usize> {
+        let (point, bit) = key.split();
+        Some(self.to_index(point)? * N + bit)
+    }
+
+    fn to_index(&self, Vec2 { x, y }: Vec2) -> Option<usize> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+
+        let x = x as usize;
+        let y = y as usize;
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        Some(y * self.width() + x)
+    }
+}
+
+impl Index<Vec2> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, point: Vec2) -> &Self::Output {
+        match self.get(point) {
+            Some(true) => &true,
+            Some(false) => &false,
+            None => panic!("Point out of bounds"),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+use crate::{Glyph, Grid, Vec2};
+
+/// The cells that differ between two grids of the same size.
+pub struct GridDiff<'a, T> {
+    old: &'a Grid<T>,
+    new: &'a Grid<T>,
+    changed: Vec<Vec2>,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Compare this grid with another one of the same size.
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> GridDiff<'a, T> {
+        assert_eq!(
+            (self.width(), self.height()),
+            (other.width(), other.height()),
+            "Grids have different sizes"
+        );
+        let changed = self.points().filter(|&p| self[p] != other[p]).collect();
+        GridDiff {
+            old: self,
+            new: other,
+            changed,
+        }
+    }
+}
+
+impl<'a, T> GridDiff<'a, T> {
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changed.len()
+    }
+
+    /// Iterate over the changed cells with their old and new values.
+    pub fn changes(&self) -> impl Iterator<Item = (Vec2, &'a T, &'a T)> + '_ {
+        self.changed
+            .iter()
+            .map(|&p| (p, &self.old[p], &self.new[p]))
+    }
+}
+
+impl<T: Glyph> GridDiff<'_, T> {
+    /// Render both grids next to each other, marking the rows that differ.
+    pub fn side_by_side(&self) -> String {
+        let mut out = String::new();
+        let width = self.old.width();
+        out.push_str(&format!("{:width$}   {:width$}\n", "old", "new"));
+        for (y, (old, new)) in self.old.rows().zip(self.new.rows()).enumerate() {
+            let old: String = old.iter().map(Glyph::glyph).collect();
+            let new: String = new.iter().map(Glyph::glyph).collect();
+            let marker = if self.changed.iter().any(|p| p.y == y as isize) {
+                '<'
+            } else {
+                ' '
+            };
+            out.push_str(&format!("{old} | {new} {marker}\n"));
+        }
+        out
+    }
+
+    /// Render the new grid with the changed cells in inverse video.
+    pub fn highlighted(&self) -> String {
+        let mut changed = self.new.with_bits::<1>();
+        for &p in &self.changed {
+            changed.insert(p);
+        }
+
+        let mut out = String::new();
+        for p in self.new.points() {
+            if p.x == 0 && p.y > 0 {
+                out.push('\n');
+            }
+            let c = self.new[p].glyph();
+            if changed[p] {
+                out.push_str(&format!("\x1b[7m{c}\x1b[0m"));
+            } else {
+                out.push(c);
+            }
+        }
+        out.push('\n');
+        out
+    }
+}
+
+impl<T: Glyph> fmt::Display for GridDiff<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} cells differ", self.len())?;
+        for (p, old, new) in self.changes().take(20) {
+            writeln!(
+                f,
+                "  ({}, {}): {:?} -> {:?}",
+                p.x,
+                p.y,
+                old.glyph(),
+                new.glyph()
+            )?;
+        }
+        if self.len() > 20 {
+            writeln!(f, "  ...")?;
+        }
+        write!(f, "{}", self.side_by_side())
+    }
+}
+
+/// Assert that two grids are equal, printing a diff of the cells that differ if they are not.
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if (left.width(), left.height()) != (right.width(), right.height()) {
+            panic!(
+                "grids have different sizes: {}x{} != {}x{}",
+                left.width(),
+                left.height(),
+                right.width(),
+                right.height()
+            );
+        }
+        let diff = left.diff(right);
+        if !diff.is_empty() {
+            panic!("grids are not equal: {diff}");
+        }
+    }};
+}
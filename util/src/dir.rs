@@ -1,4 +1,8 @@
-use crate::{Vec2, DOWN, LEFT, RIGHT, UP};
+use std::{fmt, str::FromStr};
+
+use crate::{
+    DOWN, DOWN_LEFT, DOWN_RIGHT, LEFT, RIGHT, UP, UP_LEFT, UP_RIGHT, Vec2, Vec3, vec2, vec3,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Dir {
@@ -8,20 +12,73 @@ pub enum Dir {
     Right,
 }
 
-impl From<u8> for Dir {
-    fn from(byte: u8) -> Self {
-        Dir::from(byte as char)
+/// Error returned when a direction can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirError(String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+impl TryFrom<u8> for Dir {
+    type Error = ParseDirError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        Dir::try_from(byte as char)
+    }
+}
+
+impl TryFrom<char> for Dir {
+    type Error = ParseDirError;
+
+    /// Parse an arrow (`^v<>`), a letter (`UDLR`) or a compass point (`NSEW`).
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Self::from_arrow(c)
+            .or_else(|_| Self::from_letter(c))
+            .or_else(|_| Self::from_compass(c))
+            .map_err(|_| ParseDirError(c.to_string()))
     }
 }
 
-impl From<char> for Dir {
-    fn from(c: char) -> Self {
+impl FromStr for Dir {
+    type Err = ParseDirError;
+
+    /// Parse a single character accepted by `TryFrom<char>`, or a word such as `up` or `north`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Dir::try_from(c);
+        }
+        match s.to_ascii_lowercase().as_str() {
+            "up" | "north" => Ok(Self::Up),
+            "down" | "south" => Ok(Self::Down),
+            "left" | "west" => Ok(Self::Left),
+            "right" | "east" => Ok(Self::Right),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+/// A turn relative to the current direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ParseDirError;
+
+    /// Parse `L` or `R`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '^' => Self::Up,
-            'v' => Self::Down,
-            '<' => Self::Left,
-            '>' => Self::Right,
-            _ => unreachable!("Invalid direction"),
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(ParseDirError(c.to_string())),
         }
     }
 }
@@ -31,6 +88,54 @@ impl Dir {
         [Self::Up, Self::Down, Self::Left, Self::Right].into_iter()
     }
 
+    /// Parse one of `^v<>`.
+    pub fn from_arrow(c: char) -> Result<Self, ParseDirError> {
+        match c {
+            '^' => Ok(Self::Up),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => Err(ParseDirError(c.to_string())),
+        }
+    }
+
+    /// Parse one of `UDLR`.
+    pub fn from_letter(c: char) -> Result<Self, ParseDirError> {
+        match c {
+            'U' => Ok(Self::Up),
+            'D' => Ok(Self::Down),
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(ParseDirError(c.to_string())),
+        }
+    }
+
+    /// Parse one of `NSEW`, with north pointing up.
+    pub fn from_compass(c: char) -> Result<Self, ParseDirError> {
+        match c {
+            'N' => Ok(Self::Up),
+            'S' => Ok(Self::Down),
+            'W' => Ok(Self::Left),
+            'E' => Ok(Self::Right),
+            _ => Err(ParseDirError(c.to_string())),
+        }
+    }
+
+    /// Parse a string of directions such as `<^^>v`, ignoring whitespace and line breaks.
+    pub fn parse_moves(s: &str) -> Result<Vec<Self>, ParseDirError> {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(Dir::try_from)
+            .collect()
+    }
+
+    pub const fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
     pub const fn turn_left(self) -> Self {
         match self {
             Self::Up => Self::Left,
@@ -67,3 +172,194 @@ impl Dir {
         }
     }
 }
+
+impl Vec2 {
+    pub fn neighbor8(self, dir: Dir8) -> Self {
+        self + dir.unit_vector()
+    }
+}
+
+/// One of the eight compass directions, with y pointing down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::Up => Self::Up,
+            Dir::Down => Self::Down,
+            Dir::Left => Self::Left,
+            Dir::Right => Self::Right,
+        }
+    }
+}
+
+impl TryFrom<Dir8> for Dir {
+    /// Diagonal directions are returned as the error.
+    type Error = Dir8;
+
+    fn try_from(dir: Dir8) -> Result<Self, Self::Error> {
+        match dir {
+            Dir8::Up => Ok(Self::Up),
+            Dir8::Down => Ok(Self::Down),
+            Dir8::Left => Ok(Self::Left),
+            Dir8::Right => Ok(Self::Right),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl Dir8 {
+    /// All directions in clockwise order, starting from up.
+    const CLOCKWISE: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    /// The four diagonal directions.
+    pub fn diagonals() -> impl Iterator<Item = Self> {
+        Self::all().filter(|dir| dir.is_diagonal())
+    }
+
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    const fn rotate(self, eighths: usize) -> Self {
+        Self::CLOCKWISE[(self as usize + eighths) % 8]
+    }
+
+    pub const fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub const fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turn 45 degrees counter-clockwise.
+    pub const fn turn_left_45(self) -> Self {
+        self.rotate(7)
+    }
+
+    /// Turn 45 degrees clockwise.
+    pub const fn turn_right_45(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub const fn inverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub const fn unit_vector(self) -> Vec2 {
+        match self {
+            Self::Up => UP,
+            Self::UpRight => UP_RIGHT,
+            Self::Right => RIGHT,
+            Self::DownRight => DOWN_RIGHT,
+            Self::Down => DOWN,
+            Self::DownLeft => DOWN_LEFT,
+            Self::Left => LEFT,
+            Self::UpLeft => UP_LEFT,
+        }
+    }
+}
+
+/// A direction on a grid of pointy-topped hexagons, whose rows run east to west.
+///
+/// Hexes are addressed with axial coordinates `(q, r)` stored in a [`Vec2`], where `q` grows
+/// to the east and `r` grows to the south-east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDir {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDir {
+    /// All directions in clockwise order, starting from east.
+    const CLOCKWISE: [Self; 6] = [
+        Self::East,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+        Self::NorthEast,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    const fn rotate(self, sixths: usize) -> Self {
+        Self::CLOCKWISE[(self as usize + sixths) % 6]
+    }
+
+    /// Turn 60 degrees counter-clockwise.
+    pub const fn turn_left(self) -> Self {
+        self.rotate(5)
+    }
+
+    /// Turn 60 degrees clockwise.
+    pub const fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub const fn inverse(self) -> Self {
+        self.rotate(3)
+    }
+
+    /// The step in axial coordinates.
+    pub const fn unit_vector(self) -> Vec2 {
+        match self {
+            Self::East => vec2(1, 0),
+            Self::SouthEast => vec2(0, 1),
+            Self::SouthWest => vec2(-1, 1),
+            Self::West => vec2(-1, 0),
+            Self::NorthWest => vec2(0, -1),
+            Self::NorthEast => vec2(1, -1),
+        }
+    }
+
+    /// Convert axial coordinates to cube coordinates, whose components sum to zero.
+    pub const fn to_cube(axial: Vec2) -> Vec3 {
+        vec3(axial.x, -axial.x - axial.y, axial.y)
+    }
+
+    /// Convert cube coordinates back to axial coordinates.
+    pub const fn from_cube(cube: Vec3) -> Vec2 {
+        vec2(cube.x, cube.z)
+    }
+
+    /// Number of steps between two hexes given in axial coordinates.
+    pub fn distance(a: Vec2, b: Vec2) -> isize {
+        Self::to_cube(a - b).manhattan() / 2
+    }
+
+    /// Iterate over the 6 hexes around the given one.
+    pub fn neighbors(axial: Vec2) -> impl Iterator<Item = Vec2> {
+        Self::all().map(move |dir| axial + dir.unit_vector())
+    }
+}
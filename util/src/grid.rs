@@ -6,7 +6,7 @@ use std::{
 
 use crate::Vec2;
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     data: Vec<T>,
     cols: usize,
@@ -91,6 +91,13 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Create a new grid from row-major values and the number of columns.
+    pub fn from_vec(data: Vec<T>, width: usize) -> Self {
+        assert!(width > 0);
+        assert_eq!(data.len() % width, 0);
+        Self { data, cols: width }
+    }
+
     pub fn rows(&self) -> impl Iterator<Item = &[T]> {
         self.data.chunks_exact(self.cols)
     }
@@ -150,6 +157,75 @@ impl<T> Grid<T> {
         self.data.swap(i, j);
     }
 
+    /// Create a new grid with rows and columns swapped.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = self.height();
+        let data = (0..self.cols)
+            .flat_map(|x| (0..rows).map(move |y| self.data[y * self.cols + x].clone()))
+            .collect();
+        Self { data, cols: rows }
+    }
+
+    /// Create a new grid rotated 90 degrees clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.transpose();
+        grid.flip_horizontal();
+        grid
+    }
+
+    /// Create a new grid rotated 90 degrees counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.transpose();
+        grid.flip_vertical();
+        grid
+    }
+
+    /// Mirror the grid left to right in place.
+    pub fn flip_horizontal(&mut self) {
+        for row in self.data.chunks_exact_mut(self.cols) {
+            row.reverse();
+        }
+    }
+
+    /// Mirror the grid top to bottom in place.
+    pub fn flip_vertical(&mut self) {
+        let rows = self.height();
+        for y in 0..rows / 2 {
+            let (top, bottom) = self.data.split_at_mut((rows - 1 - y) * self.cols);
+            top[y * self.cols..(y + 1) * self.cols].swap_with_slice(&mut bottom[..self.cols]);
+        }
+    }
+
+    /// All distinct rotations and reflections of this grid, starting with the grid itself.
+    pub fn orientations(&self) -> Vec<Self>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut orientations: Vec<Self> = vec![];
+        let mut grid = self.clone();
+        for _ in 0..4 {
+            let mut flipped = grid.clone();
+            flipped.flip_horizontal();
+            let next = grid.rotate_right();
+            for g in [grid, flipped] {
+                if !orientations.contains(&g) {
+                    orientations.push(g);
+                }
+            }
+            grid = next;
+        }
+        orientations
+    }
+
     /// Number of rows in the grid.
     pub fn height(&self) -> usize {
         self.data.len() / self.cols
@@ -161,7 +237,7 @@ impl<T> Grid<T> {
     }
 
     /// Iterate over the points in the grid.
-    pub fn points(&self) -> impl Iterator<Item = Vec2> {
+    pub fn points(&self) -> impl Iterator<Item = Vec2> + use<T> {
         let rows = self.height() as isize;
         let cols = self.width() as isize;
         (0..rows).flat_map(move |y| (0..cols).map(move |x| Vec2 { x, y }))
@@ -189,6 +265,25 @@ impl<T> Grid<T> {
         self.to_index(point).is_some()
     }
 
+    /// Iterate over the points from `start` (inclusive) in steps of `step` until leaving the grid.
+    pub fn ray(&self, start: Vec2, step: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        assert_ne!(step, Vec2 { x: 0, y: 0 }, "Ray step must be non-zero");
+        std::iter::successors(Some(start), move |&p| Some(p + step)).take_while(|&p| self.has(p))
+    }
+
+    /// Find the first point after `start` along `step` whose value satisfies the given predicate.
+    pub fn first_hit(&self, start: Vec2, step: Vec2, filter: impl Fn(&T) -> bool) -> Option<Vec2> {
+        self.ray(start, step).skip(1).find(|&p| filter(&self[p]))
+    }
+
+    /// Find the first point satisfying the given predicate in each of the 8 directions from `start`.
+    pub fn visible_from(&self, start: Vec2, filter: impl Fn(&T) -> bool) -> Vec<Vec2> {
+        start
+            .neighbors_all()
+            .filter_map(|n| self.first_hit(start, n - start, &filter))
+            .collect()
+    }
+
     /// Find the first point in the grid that satisfies the given predicate.
     pub fn find(&self, filter: impl Fn(&T) -> bool) -> Vec2 {
         let pos = self.data.iter().position(filter).unwrap();
@@ -0,0 +1,304 @@
+use std::{
+    collections::VecDeque,
+    mem,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Grid, Vec3, vec3};
+
+/// A dense 3D grid indexed by [`Vec3`].
+///
+/// The grid covers the box from `origin` to `origin + (width, height, depth) - 1`, so points
+/// may be negative when the grid is built around a set of points.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    data: Vec<T>,
+    origin: Vec3,
+    cols: usize,
+    rows: usize,
+}
+
+impl<T> std::fmt::Debug for Grid3<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for z in 0..self.depth() {
+            writeln!(f, "z = {}", self.origin.z + z as isize)?;
+            write!(f, "{:?}", self.slice_z(self.origin.z + z as isize))?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid3<bool> {
+    /// Create a grid covering the given points, with `padding` empty cells around them.
+    /// The given points are set to true.
+    pub fn from_points(points: impl IntoIterator<Item = Vec3>, padding: usize) -> Self {
+        let points: Vec<Vec3> = points.into_iter().collect();
+        assert!(!points.is_empty());
+
+        let pad = padding as isize;
+        let min = |f: fn(&Vec3) -> isize| points.iter().map(f).min().unwrap() - pad;
+        let max = |f: fn(&Vec3) -> isize| points.iter().map(f).max().unwrap() + pad;
+        let origin = vec3(min(|p| p.x), min(|p| p.y), min(|p| p.z));
+        let size = |lo: isize, hi: isize| (hi - lo + 1) as usize;
+
+        let mut grid = Self::new(
+            size(origin.x, max(|p| p.x)),
+            size(origin.y, max(|p| p.y)),
+            size(origin.z, max(|p| p.z)),
+            false,
+        );
+        grid.origin = origin;
+        for p in points {
+            grid[p] = true;
+        }
+        grid
+    }
+}
+
+impl<T> Grid3<T> {
+    /// Create a new grid with the given dimensions and fill it with the given value.
+    ///
+    /// Panics if any dimension is zero.
+    pub fn new(width: usize, height: usize, depth: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            width > 0 && height > 0 && depth > 0,
+            "Grid3 dimensions must be non-zero"
+        );
+        Self {
+            data: vec![value; width * height * depth],
+            origin: vec3(0, 0, 0),
+            cols: width,
+            rows: height,
+        }
+    }
+
+    /// Number of cells along the x axis.
+    pub const fn width(&self) -> usize {
+        self.cols
+    }
+
+    /// Number of cells along the y axis.
+    pub const fn height(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of cells along the z axis.
+    pub fn depth(&self) -> usize {
+        self.data.len() / (self.cols * self.rows)
+    }
+
+    /// The smallest point in the grid.
+    pub const fn origin(&self) -> Vec3 {
+        self.origin
+    }
+
+    /// Replace the value at the given point with the given value, returning the old value.
+    ///
+    /// If the point is out of bounds, return None.
+    pub fn replace(&mut self, point: Vec3, value: T) -> Option<T> {
+        self.get_mut(point).map(|dest| mem::replace(dest, value))
+    }
+
+    /// Create a new grid with the same bounds as this one, and fill it with given value.
+    pub fn with_fill<U>(&self, value: U) -> Grid3<U>
+    where
+        U: Clone,
+    {
+        Grid3 {
+            data: vec![value; self.data.len()],
+            origin: self.origin,
+            cols: self.cols,
+            rows: self.rows,
+        }
+    }
+
+    /// Create a new grid by applying the given function to each value in this grid.
+    pub fn map<U, F>(&self, f: F) -> Grid3<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid3 {
+            data: self.data.iter().map(f).collect(),
+            origin: self.origin,
+            cols: self.cols,
+            rows: self.rows,
+        }
+    }
+
+    /// Iterate over the points in the grid.
+    pub fn points(&self) -> impl Iterator<Item = Vec3> + use<T> {
+        let Vec3 { x, y, z } = self.origin;
+        let (cols, rows, depth) = (
+            self.width() as isize,
+            self.height() as isize,
+            self.depth() as isize,
+        );
+        (z..z + depth).flat_map(move |z| {
+            (y..y + rows).flat_map(move |y| (x..x + cols).map(move |x| vec3(x, y, z)))
+        })
+    }
+
+    /// Get the value at the given point, if it exists.
+    pub fn get(&self, point: Vec3) -> Option<&T> {
+        let i = self.to_index(point)?;
+        self.data.get(i)
+    }
+
+    /// Get a mutable reference to the value at the given point, if it exists.
+    pub fn get_mut(&mut self, point: Vec3) -> Option<&mut T> {
+        let i = self.to_index(point)?;
+        self.data.get_mut(i)
+    }
+
+    /// Check if the given point is within the bounds of the grid.
+    pub fn has(&self, point: Vec3) -> bool {
+        self.to_index(point).is_some()
+    }
+
+    /// Iterate over the 6 face neighbors of the given point.
+    pub fn neighbors(&self, point: Vec3) -> impl Iterator<Item = Vec3> + '_ {
+        point.neighbors().filter(move |&p| self.has(p))
+    }
+
+    /// Iterate over the 26 surrounding neighbors of the given point.
+    pub fn neighbors_all(&self, point: Vec3) -> impl Iterator<Item = Vec3> + '_ {
+        point.neighbors_all().filter(move |&p| self.has(p))
+    }
+
+    /// The plane at the given x, indexed by (y, z).
+    pub fn slice_x(&self, x: isize) -> Grid<&T> {
+        let Vec3 { y: y0, z: z0, .. } = self.origin;
+        let (rows, depth) = (self.height() as isize, self.depth() as isize);
+        let data = (z0..z0 + depth)
+            .flat_map(|z| (y0..y0 + rows).map(move |y| vec3(x, y, z)))
+            .map(|p| &self[p])
+            .collect();
+        Grid::from_vec(data, self.height())
+    }
+
+    /// The plane at the given y, indexed by (x, z).
+    pub fn slice_y(&self, y: isize) -> Grid<&T> {
+        let Vec3 { x: x0, z: z0, .. } = self.origin;
+        let (cols, depth) = (self.width() as isize, self.depth() as isize);
+        let data = (z0..z0 + depth)
+            .flat_map(|z| (x0..x0 + cols).map(move |x| vec3(x, y, z)))
+            .map(|p| &self[p])
+            .collect();
+        Grid::from_vec(data, self.width())
+    }
+
+    /// The plane at the given z, indexed by (x, y).
+    pub fn slice_z(&self, z: isize) -> Grid<&T> {
+        let Vec3 { x: x0, y: y0, .. } = self.origin;
+        let (cols, rows) = (self.width() as isize, self.height() as isize);
+        let data = (y0..y0 + rows)
+            .flat_map(|y| (x0..x0 + cols).map(move |x| vec3(x, y, z)))
+            .map(|p| &self[p])
+            .collect();
+        Grid::from_vec(data, self.width())
+    }
+
+    /// Find all points reachable from `start` through face neighbors that satisfy the given predicate.
+    pub fn flood_fill(&self, start: Vec3, filter: impl Fn(&T) -> bool) -> Vec<Vec3> {
+        self.flood_fill_from([start], filter)
+    }
+
+    /// Count the faces between cells that satisfy the given predicate and cells that don't.
+    /// Faces on the edge of the grid are counted too.
+    pub fn surface_area(&self, filter: impl Fn(&T) -> bool) -> usize {
+        self.points()
+            .filter(|&p| filter(&self[p]))
+            .map(|p| {
+                p.neighbors()
+                    .filter(|&n| self.get(n).is_none_or(|v| !filter(v)))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Like [`Self::surface_area`], but ignore faces of pockets that are enclosed
+    /// and can't be reached from the edge of the grid.
+    pub fn exterior_surface_area(&self, filter: impl Fn(&T) -> bool) -> usize {
+        let edge = self
+            .points()
+            .filter(|&p| p.neighbors().any(|n| !self.has(n)) && !filter(&self[p]));
+        let mut outside = self.with_fill(false);
+        for p in self.flood_fill_from(edge, |v| !filter(v)) {
+            outside[p] = true;
+        }
+
+        self.points()
+            .filter(|&p| filter(&self[p]))
+            .map(|p| {
+                p.neighbors()
+                    .filter(|&n| outside.get(n).is_none_or(|&v| v))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn flood_fill_from(
+        &self,
+        starts: impl IntoIterator<Item = Vec3>,
+        filter: impl Fn(&T) -> bool,
+    ) -> Vec<Vec3> {
+        let mut visited = self.with_fill(false);
+        let mut pending = VecDeque::new();
+        for start in starts {
+            if self.get(start).is_some_and(&filter) && !visited[start] {
+                visited[start] = true;
+                pending.push_back(start);
+            }
+        }
+
+        let mut filled = vec![];
+        while let Some(p) = pending.pop_front() {
+            filled.push(p);
+            for n in self.neighbors(p) {
+                if !visited[n] && filter(&self[n]) {
+                    visited[n] = true;
+                    pending.push_back(n);
+                }
+            }
+        }
+        filled
+    }
+
+    fn to_index(&self, point: Vec3) -> Option<usize> {
+        let x = point.x - self.origin.x;
+        let y = point.y - self.origin.y;
+        let z = point.z - self.origin.z;
+        if x < 0 || y < 0 || z < 0 {
+            return None;
+        }
+
+        let (x, y, z) = (x as usize, y as usize, z as usize);
+        if x >= self.width() || y >= self.height() || z >= self.depth() {
+            return None;
+        }
+
+        Some((z * self.height() + y) * self.width() + x)
+    }
+}
+
+impl<T> Index<Vec3> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, point: Vec3) -> &Self::Output {
+        let index = self.to_index(point).unwrap();
+        &self.data[index]
+    }
+}
+
+impl<T> IndexMut<Vec3> for Grid3<T> {
+    fn index_mut(&mut self, point: Vec3) -> &mut Self::Output {
+        let index = self.to_index(point).unwrap();
+        &mut self.data[index]
+    }
+}
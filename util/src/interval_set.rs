@@ -0,0 +1,281 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::Num;
+
+/// A set of integers stored as sorted, disjoint inclusive intervals.
+///
+/// Overlapping and adjacent intervals are merged on insertion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: Num + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Num + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    ///
+    /// Panics if the count doesn't fit in `T`, as for a set covering every `u8`.
+    pub fn count(&self) -> T {
+        let add = |a: T, b: T| {
+            assert!(a <= T::MAX - b, "Interval set count overflows");
+            a + b
+        };
+        self.intervals.iter().fold(T::ZERO, |sum, &(start, end)| {
+            // `end - start` only overflows when the interval straddles zero.
+            assert!(
+                start >= T::ZERO || end <= T::MAX + start,
+                "Interval set count overflows"
+            );
+            add(sum, add(end - start, T::ONE))
+        })
+    }
+
+    /// Iterate over the disjoint intervals in increasing order.
+    pub fn intervals(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|&(start, _)| start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|&(_, end)| end)
+    }
+
+    /// Check whether the value is in the set, in O(log n).
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Add all values of the range, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Compare without adding to `end` or subtracting from `start`, which could overflow
+        // at the limits of `T`.
+        let i = self
+            .intervals
+            .partition_point(|&(_, e)| e < start && e + T::ONE < start);
+        let j = self
+            .intervals
+            .partition_point(|&(s, _)| s <= end || s - T::ONE <= end);
+        if i < j {
+            start = start.min(self.intervals[i].0);
+            end = end.max(self.intervals[j - 1].1);
+        }
+        self.intervals.splice(i..j, [(start, end)]);
+    }
+
+    /// Remove all values of the range, splitting the intervals it cuts through.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let i = self.intervals.partition_point(|&(_, e)| e < start);
+        let j = self.intervals.partition_point(|&(s, _)| s <= end);
+        if i >= j {
+            return;
+        }
+        let mut kept = vec![];
+        if self.intervals[i].0 < start {
+            kept.push((self.intervals[i].0, start - T::ONE));
+        }
+        if self.intervals[j - 1].1 > end {
+            kept.push((end + T::ONE, self.intervals[j - 1].1));
+        }
+        self.intervals.splice(i..j, kept);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        out.extend(other.intervals());
+        out
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a_start, a_end) = self.intervals[i];
+            let (b_start, b_end) = other.intervals[j];
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for range in other.intervals() {
+            out.remove(range);
+        }
+        out
+    }
+
+    /// The values within the bounds that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let mut out = Self::new();
+        out.insert(bounds);
+        out.difference(self)
+    }
+}
+
+impl<T: Num + Ord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut out = Self::new();
+        out.extend(iter);
+        out
+    }
+}
+
+impl<T: Num + Ord> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/// Error returned when an interval can't be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntervalError(String);
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid interval: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseIntervalError {}
+
+impl<T: Num + Ord + FromStr> FromStr for IntervalSet<T> {
+    type Err = ParseIntervalError;
+
+    /// Parse a list of `a-b` intervals separated by commas, spaces or line breaks.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let err = || ParseIntervalError(part.to_string());
+                // Skip the first character so that a negative start isn't taken as the separator.
+                let split = part
+                    .char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| c == '-')
+                    .ok_or_else(err)?
+                    .0;
+                let start = part[..split].parse().map_err(|_| err())?;
+                let end = part[split + 1..].parse().map_err(|_| err())?;
+                Ok(start..=end)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intervals<T: Num + Ord>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+        set.intervals().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent() {
+        let mut set: IntervalSet<i64> = [1..=3, 7..=9].into_iter().collect();
+        set.insert(4..=5);
+        assert_eq!(intervals(&set), [1..=5, 7..=9]);
+        set.insert(6..=6);
+        assert_eq!(intervals(&set), [1..=9]);
+        set.insert(-5..=-3);
+        set.insert(0..=20);
+        assert_eq!(intervals(&set), [-5..=-3, 0..=20]);
+        assert_eq!(set.count(), 24);
+    }
+
+    #[test]
+    fn remove_splits_intervals() {
+        let mut set: IntervalSet<i64> = "1-10,20-30".parse().unwrap();
+        set.remove(4..=6);
+        assert_eq!(intervals(&set), [1..=3, 7..=10, 20..=30]);
+        set.remove(9..=25);
+        assert_eq!(intervals(&set), [1..=3, 7..=8, 26..=30]);
+        assert!(set.contains(8) && !set.contains(9) && set.contains(26));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IntervalSet<i64> = "-4--2,1-3,5-9".parse().unwrap();
+        let b: IntervalSet<i64> = "0-6".parse().unwrap();
+        assert_eq!(intervals(&a.intersection(&b)), [1..=3, 5..=6]);
+        assert_eq!(intervals(&a.union(&b)), [-4..=-2, 0..=9]);
+        assert_eq!(intervals(&a.difference(&b)), [-4..=-2, 7..=9]);
+        assert_eq!(
+            intervals(&a.complement(-10..=10)),
+            [-10..=-5, -1..=0, 4..=4, 10..=10]
+        );
+    }
+
+    #[test]
+    fn limits_of_the_type() {
+        let mut set = IntervalSet::<u8>::new();
+        set.insert(250..=255);
+        set.insert(0..=3);
+        assert_eq!(intervals(&set), [0..=3, 250..=255]);
+        set.insert(4..=249);
+        assert_eq!(intervals(&set), [0..=255]);
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(intervals(&set), [1..=254]);
+        assert_eq!(set.count(), 254);
+        assert_eq!(intervals(&set.complement(0..=255)), [0..=0, 255..=255]);
+        assert_eq!(
+            intervals(&IntervalSet::new().complement(0..=u8::MAX)),
+            [0..=255]
+        );
+
+        let set: IntervalSet<i8> = [-128..=-1, 1..=127].into_iter().collect();
+        assert_eq!(intervals(&set.complement(-128..=127)), [0..=0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Interval set count overflows")]
+    fn count_overflow() {
+        let set: IntervalSet<u8> = [0..=255].into_iter().collect();
+        set.count();
+    }
+
+    #[test]
+    #[should_panic(expected = "Interval set count overflows")]
+    fn count_overflow_signed() {
+        let set: IntervalSet<i8> = [-100..=100].into_iter().collect();
+        set.count();
+    }
+}
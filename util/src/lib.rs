@@ -1,15 +1,46 @@
+mod automaton;
+mod bit_grid;
 mod clique;
+mod diff;
 mod dir;
 mod graph;
 mod grid;
+mod grid3;
+mod image;
+mod interval_set;
+mod line;
+mod neighborhood;
+mod ocr;
 mod parse;
+mod pattern;
+mod polygon;
+mod recorder;
+mod rect;
+mod render;
+mod svg;
 mod topological_sort;
 mod union_find;
 mod vector;
+mod weighted_graph;
 
+pub use automaton::*;
+pub use bit_grid::*;
+pub use diff::*;
 pub use dir::*;
 pub use graph::*;
 pub use grid::*;
+pub use grid3::*;
+pub use image::*;
+pub use interval_set::*;
+pub use line::*;
+pub use neighborhood::*;
+pub use ocr::*;
 pub use parse::*;
+pub use polygon::*;
+pub use recorder::*;
+pub use rect::*;
+pub use render::*;
+pub use svg::*;
 pub use union_find::*;
 pub use vector::*;
+pub use weighted_graph::*;
@@ -0,0 +1,65 @@
+use crate::{Vec2, vec2};
+
+/// Iterate over the points from `start` to `end`, both included.
+///
+/// Panics if the line is not horizontal, vertical or diagonal. Use [`bresenham`] for other slopes.
+pub fn line(start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2> {
+    line_exclusive(start, end).chain(std::iter::once(end))
+}
+
+/// Iterate over the points from `start` up to but not including `end`.
+///
+/// Panics if the line is not horizontal, vertical or diagonal. Use [`bresenham`] for other slopes.
+pub fn line_exclusive(start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2> {
+    let d = end - start;
+    assert!(
+        d.x == 0 || d.y == 0 || d.x.abs() == d.y.abs(),
+        "Line must be horizontal, vertical or diagonal"
+    );
+    let step = d.signum();
+    (0..d.chebyshev()).map(move |i| start + step * i)
+}
+
+/// Iterate over the points of the line from `start` to `end` with any slope, both included.
+/// The points are the cells closest to the ideal line, with one point per step along the
+/// longer axis.
+pub fn bresenham(start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2> {
+    let d = (end - start).abs();
+    let step = (end - start).signum();
+    let mut p = start;
+    let mut err = d.x - d.y;
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let current = p;
+        if p == end {
+            done = true;
+        } else {
+            let e2 = 2 * err;
+            if e2 > -d.y {
+                err -= d.y;
+                p.x += step.x;
+            }
+            if e2 < d.x {
+                err += d.x;
+                p.y += step.y;
+            }
+        }
+        Some(current)
+    })
+}
+
+/// Iterate over the lattice points lying exactly on the segment from `start` to `end`, both
+/// included.
+pub fn lattice_points(start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2> {
+    let d = end - start;
+    let step = d.reduced();
+    let count = if step == vec2(0, 0) {
+        0
+    } else {
+        d.chebyshev() / step.chebyshev()
+    };
+    (0..=count).map(move |i| start + step * i)
+}
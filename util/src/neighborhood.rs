@@ -0,0 +1,81 @@
+use crate::{Grid, Vec2, vec2};
+
+/// A set of offsets around a point, excluding the point itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Neighborhood {
+    offsets: Vec<Vec2>,
+}
+
+impl Neighborhood {
+    /// All points within the given Manhattan distance, i.e. a diamond.
+    /// Radius 1 gives the 4 orthogonal neighbours.
+    pub fn von_neumann(radius: usize) -> Self {
+        Self::square(radius, |d| d.manhattan() <= radius as isize)
+    }
+
+    /// All points within the given Chebyshev distance, i.e. a square.
+    /// Radius 1 gives the 8 surrounding neighbours.
+    pub fn moore(radius: usize) -> Self {
+        Self::square(radius, |_| true)
+    }
+
+    /// All points at exactly the given Manhattan distance, i.e. the outline of a diamond.
+    pub fn ring(distance: usize) -> Self {
+        Self::square(distance, |d| d.manhattan() == distance as isize)
+    }
+
+    /// The 8 moves of a chess knight.
+    pub fn knight() -> Self {
+        Self::square(2, |d| d.x.abs() * d.y.abs() == 2)
+    }
+
+    /// Any list of offsets. The zero offset is dropped.
+    pub fn custom(offsets: impl IntoIterator<Item = Vec2>) -> Self {
+        Self {
+            offsets: offsets.into_iter().filter(|&d| d != vec2(0, 0)).collect(),
+        }
+    }
+
+    pub fn offsets(&self) -> &[Vec2] {
+        &self.offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Iterate over the neighbours of the given point.
+    pub fn around(&self, point: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.offsets.iter().map(move |&d| point + d)
+    }
+
+    fn square(radius: usize, filter: impl Fn(Vec2) -> bool) -> Self {
+        let r = radius as isize;
+        Self::custom(
+            (-r..=r)
+                .flat_map(|y| (-r..=r).map(move |x| vec2(x, y)))
+                .filter(|&d| filter(d)),
+        )
+    }
+}
+
+impl Vec2 {
+    pub fn neighbors_with(self, neighborhood: &Neighborhood) -> impl Iterator<Item = Self> + '_ {
+        neighborhood.around(self)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Iterate over the neighbors of the given point in the given neighborhood.
+    pub fn neighbors_with<'a>(
+        &'a self,
+        point: Vec2,
+        neighborhood: &'a Neighborhood,
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        neighborhood.around(point).filter(move |&p| self.has(p))
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use crate::{Grid, vec2};
+
+/// Letters of the 4x6 font. Some letters are narrower or wider than 4 columns.
+const SMALL: [(char, &str); 18] = [
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', ".###|..#.|..#.|..#.|..#.|.###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+/// Letters of the 6x10 font.
+#[rustfmt::skip]
+const LARGE: [(char, &str); 15] = [
+    ('A', "..##..|.#..#.|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#"),
+    ('B', "#####.|#....#|#....#|#....#|#####.|#....#|#....#|#....#|#....#|#####."),
+    ('C', ".####.|#....#|#.....|#.....|#.....|#.....|#.....|#.....|#....#|.####."),
+    ('E', "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|######"),
+    ('F', "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|#....."),
+    ('G', ".####.|#....#|#.....|#.....|#.....|#..###|#....#|#....#|#...##|.###.#"),
+    ('H', "#....#|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#|#....#"),
+    ('J', "...###|....#.|....#.|....#.|....#.|....#.|....#.|#...#.|#...#.|.###.."),
+    ('K', "#....#|#...#.|#..#..|#.#...|##....|##....|#.#...|#..#..|#...#.|#....#"),
+    ('L', "#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|######"),
+    ('N', "#....#|##...#|##...#|#.#..#|#.#..#|#..#.#|#..#.#|#...##|#...##|#....#"),
+    ('P', "#####.|#....#|#....#|#....#|#####.|#.....|#.....|#.....|#.....|#....."),
+    ('R', "#####.|#....#|#....#|#....#|#####.|#..#..|#...#.|#...#.|#....#|#....#"),
+    ('X', "#....#|#....#|.#..#.|.#..#.|..##..|..##..|.#..#.|.#..#.|#....#|#....#"),
+    ('Z', "######|.....#|.....#|....#.|...#..|..#...|.#....|#.....|#.....|######"),
+];
+
+/// Glyphs that could not be read, along with the partially decoded text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrError {
+    /// The decoded text, with `?` in place of each unknown glyph.
+    pub text: String,
+    /// Renderings of the unknown glyphs, one row per line.
+    pub unknown: Vec<String>,
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Unknown glyphs in {:?}:", self.text)?;
+        for glyph in &self.unknown {
+            writeln!(f, "{glyph}\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+impl Grid<bool> {
+    /// Read the block letters drawn with true cells.
+    pub fn ocr(&self) -> Result<String, OcrError> {
+        let rows: Vec<usize> = (0..self.height())
+            .filter(|&y| (0..self.width()).any(|x| self[vec2(x as isize, y as isize)]))
+            .collect();
+        let (Some(&top), Some(&bottom)) = (rows.first(), rows.last()) else {
+            return Ok(String::new());
+        };
+
+        let font: &[(char, &str)] = if bottom - top + 1 > 6 { &LARGE } else { &SMALL };
+
+        let lit = |x: usize, y: usize| self[vec2(x as isize, y as isize)];
+        let blank = |x: usize| (top..=bottom).all(|y| !lit(x, y));
+
+        let mut text = String::new();
+        let mut unknown = vec![];
+        let mut x = 0;
+        while x < self.width() {
+            if blank(x) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < self.width() && !blank(x) {
+                x += 1;
+            }
+
+            let glyph = (top..=bottom)
+                .map(|y| {
+                    (start..x)
+                        .map(|x| if lit(x, y) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("|");
+            match font.iter().find(|(_, g)| trim(g) == glyph) {
+                Some(&(c, _)) => text.push(c),
+                None => {
+                    text.push('?');
+                    unknown.push(glyph.replace('|', "\n"));
+                }
+            }
+        }
+
+        if unknown.is_empty() {
+            Ok(text)
+        } else {
+            Err(OcrError { text, unknown })
+        }
+    }
+}
+
+/// Read the block letters drawn with `#` in the given text.
+pub fn ocr(str: &str) -> Result<String, OcrError> {
+    let width = str.lines().map(|line| line.len()).max().unwrap_or(0);
+    let mut grid = Grid::new(width.max(1), str.lines().count().max(1), false);
+    for (y, line) in str.lines().enumerate() {
+        for (x, c) in line.bytes().enumerate() {
+            grid[vec2(x as isize, y as isize)] = c == b'#';
+        }
+    }
+    grid.ocr()
+}
+
+/// Drop the columns of a glyph that are blank on every row.
+fn trim(glyph: &str) -> String {
+    let rows: Vec<&[u8]> = glyph.split('|').map(str::as_bytes).collect();
+    let inked = |x: usize| rows.iter().any(|row| row[x] == b'#');
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| inked(x)).unwrap_or(0);
+    let end = (0..width).rfind(|&x| inked(x)).map_or(0, |x| x + 1);
+    rows.iter()
+        .map(|row| std::str::from_utf8(&row[start..end]).unwrap())
+        .collect::<Vec<_>>()
+        .join("|")
+}
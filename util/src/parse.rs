@@ -19,6 +19,8 @@ pub trait Parse {
     where
         F: Fn([U; N]) -> T,
         U: FromStr;
+
+    fn column_groups(&self) -> Vec<ColumnGroup>;
 }
 
 impl Parse for str {
@@ -64,4 +66,178 @@ impl Parse for str {
             })
             .collect()
     }
+
+    /// Split an ASCII text block into groups of columns separated by columns that are blank on every line.
+    ///
+    /// Panics if the text isn't ASCII, as columns are counted in bytes.
+    fn column_groups(&self) -> Vec<ColumnGroup> {
+        assert!(self.is_ascii(), "Column groups need ASCII text");
+        let lines: Vec<&[u8]> = self.lines().map(str::as_bytes).collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let blank = |col: usize| {
+            lines
+                .iter()
+                .all(|line| line.get(col).is_none_or(|c| c.is_ascii_whitespace()))
+        };
+
+        let mut groups = vec![];
+        let mut col = 0;
+        while col < width {
+            if blank(col) {
+                col += 1;
+                continue;
+            }
+            let start = col;
+            while col < width && !blank(col) {
+                col += 1;
+            }
+            let rows = lines
+                .iter()
+                .map(|line| {
+                    (start..col)
+                        .map(|c| line.get(c).map_or(' ', |&b| b as char))
+                        .collect()
+                })
+                .collect();
+            groups.push(ColumnGroup { start, rows });
+        }
+        groups
+    }
+}
+
+/// How the text on a line of a [`ColumnGroup`] sits within the group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    /// Padded on the right.
+    Left,
+    /// Padded on the left.
+    Right,
+    /// Spans the whole group width.
+    Full,
+    /// Padded on both sides, or blank.
+    None,
+}
+
+/// A block of adjacent columns, with every line padded with spaces to the group width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnGroup {
+    start: usize,
+    rows: Vec<String>,
+}
+
+impl ColumnGroup {
+    /// Index of the first column of this group in the original text.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    /// The lines of this group, read left to right.
+    pub fn rows(&self) -> impl Iterator<Item = &str> {
+        self.rows.iter().map(String::as_str)
+    }
+
+    /// The columns of this group, read top to bottom.
+    pub fn cols(&self) -> impl Iterator<Item = String> + '_ {
+        (0..self.width()).map(|col| {
+            self.rows
+                .iter()
+                .map(|row| row.as_bytes()[col] as char)
+                .collect()
+        })
+    }
+
+    /// Parse each non-blank line as a number.
+    pub fn row_numbers<T>(&self) -> Vec<T>
+    where
+        T: FromStr,
+    {
+        parse_trimmed(self.rows())
+    }
+
+    /// Parse each non-blank column as a number.
+    pub fn col_numbers<T>(&self) -> Vec<T>
+    where
+        T: FromStr,
+    {
+        parse_trimmed(self.cols())
+    }
+
+    /// Alignment of the given line within the group.
+    pub fn align(&self, row: usize) -> Align {
+        let row = self.rows[row].as_bytes();
+        let padded_left = row.first().is_none_or(u8::is_ascii_whitespace);
+        let padded_right = row.last().is_none_or(u8::is_ascii_whitespace);
+        match (padded_left, padded_right) {
+            (false, true) => Align::Left,
+            (true, false) => Align::Right,
+            (false, false) => Align::Full,
+            (true, true) => Align::None,
+        }
+    }
+}
+
+fn parse_trimmed<T, S>(values: impl Iterator<Item = S>) -> Vec<T>
+where
+    T: FromStr,
+    S: AsRef<str>,
+{
+    values
+        .filter_map(|v| {
+            let v = v.as_ref().trim();
+            (!v.is_empty()).then(|| v.parse().ok().unwrap())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_groups_split_on_blank_columns() {
+        let groups = "123 328  51\n 45 64  387\n  6 98  215\n*   +   *  ".column_groups();
+        assert_eq!(
+            groups.iter().map(ColumnGroup::start).collect::<Vec<_>>(),
+            [0, 4, 8]
+        );
+
+        let first = &groups[0];
+        assert_eq!(first.width(), 3);
+        assert_eq!(
+            first.rows().collect::<Vec<_>>(),
+            ["123", " 45", "  6", "*  "]
+        );
+        assert_eq!(first.cols().collect::<Vec<_>>(), ["1  *", "24  ", "356 "]);
+        assert_eq!(
+            (0..4).map(|row| first.align(row)).collect::<Vec<_>>(),
+            [Align::Full, Align::Right, Align::Right, Align::Left]
+        );
+    }
+
+    #[test]
+    fn column_group_numbers() {
+        let groups = "12  51\n 4 387\n   215".column_groups();
+        assert_eq!(groups[0].row_numbers::<u32>(), [12, 4]);
+        assert_eq!(groups[0].col_numbers::<u32>(), [1, 24]);
+        assert_eq!(groups[1].col_numbers::<u32>(), [32, 581, 175]);
+    }
+
+    #[test]
+    fn column_groups_treat_tabs_as_blank() {
+        let groups = "1\t2\n3 \t".column_groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].rows().collect::<Vec<_>>(), ["1", "3"]);
+        assert_eq!(groups[1].rows().collect::<Vec<_>>(), ["2", "\t"]);
+        assert_eq!(groups[1].align(1), Align::None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column groups need ASCII text")]
+    fn column_groups_reject_non_ascii() {
+        "é 1\n2 3".column_groups();
+    }
 }
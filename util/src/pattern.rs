@@ -0,0 +1,79 @@
+use crate::{Grid, Neighborhood, Vec2, vec2};
+
+impl Grid<Option<u8>> {
+    /// Create a pattern from a string of bytes, where the given byte matches any cell.
+    pub fn pattern_from_bytes(str: &str, wildcard: u8) -> Self {
+        Grid::from_bytes(str).map(|&c| (c != wildcard).then_some(c))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Find the top-left positions where the given pattern matches.
+    /// `None` cells in the pattern match anything.
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>) -> Vec<Vec2> {
+        if pattern.width() > self.width() || pattern.height() > self.height() {
+            return vec![];
+        }
+
+        let rows = (self.height() - pattern.height()) as isize;
+        let cols = (self.width() - pattern.width()) as isize;
+        (0..=rows)
+            .flat_map(|y| (0..=cols).map(move |x| vec2(x, y)))
+            .filter(|&pos| self.matches_at(pos, pattern))
+            .collect()
+    }
+
+    /// Find the pattern under all of its distinct rotations and reflections.
+    ///
+    /// Returns the top-left position of each match along with the index of the matching
+    /// orientation in `pattern.orientations()`.
+    pub fn find_pattern_oriented(&self, pattern: &Grid<Option<T>>) -> Vec<(Vec2, usize)>
+    where
+        T: Clone,
+    {
+        pattern
+            .orientations()
+            .iter()
+            .enumerate()
+            .flat_map(|(i, p)| self.find_pattern(p).into_iter().map(move |pos| (pos, i)))
+            .collect()
+    }
+
+    /// Find the word along rows, columns and diagonals in both directions.
+    ///
+    /// Returns the start of each match and the step from one letter to the next.
+    /// A one-letter word has no direction, so it matches once per cell with a zero step.
+    pub fn find_word(&self, word: &[T]) -> Vec<(Vec2, Vec2)> {
+        let Some(first) = word.first() else {
+            return vec![];
+        };
+        if word.len() == 1 {
+            return self
+                .points()
+                .filter(|&p| self[p] == *first)
+                .map(|p| (p, vec2(0, 0)))
+                .collect();
+        }
+
+        let directions = Neighborhood::moore(1);
+        let mut found = vec![];
+        for start in self.points() {
+            if self[start] != *first {
+                continue;
+            }
+            for &step in directions.offsets() {
+                if self.get_range(start, step, word.len()).eq(word) {
+                    found.push((start, step));
+                }
+            }
+        }
+        found
+    }
+
+    fn matches_at(&self, pos: Vec2, pattern: &Grid<Option<T>>) -> bool {
+        pattern.points().all(|p| match &pattern[p] {
+            Some(expected) => self[pos + p] == *expected,
+            None => true,
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Vec2, gcd, vec2};
+
+/// A simple polygon with lattice point vertices, given in order around its boundary.
+///
+/// The polygon is closed: points on its boundary count as inside.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Vec2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vec2>) -> Self {
+        assert!(!vertices.is_empty(), "Polygon has no vertices");
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Vec2] {
+        &self.vertices
+    }
+
+    /// Iterate over the edges, including the one from the last vertex back to the first.
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Twice the signed area, positive if the vertices are clockwise when y points down.
+    pub fn signed_doubled_area(&self) -> isize {
+        self.edges().map(|(a, b)| a.cross(b)).sum()
+    }
+
+    /// Twice the area. Always an integer, unlike the area itself.
+    pub fn doubled_area(&self) -> isize {
+        self.signed_doubled_area().abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.doubled_area() as f64 / 2.0
+    }
+
+    /// The number of lattice points on the boundary.
+    pub fn boundary_points(&self) -> isize {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()) as isize)
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside, using Pick's theorem.
+    pub fn interior_points(&self) -> isize {
+        (self.doubled_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// The number of lattice points inside or on the boundary. For a polygon traced through
+    /// the centers of grid cells, this is the number of cells it covers.
+    pub fn lattice_points(&self) -> isize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn on_boundary(&self, p: Vec2) -> bool {
+        self.edges().any(|(a, b)| on_segment(p, a, b))
+    }
+
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.contains_scaled(p, 1)
+    }
+
+    /// Whether the whole axis-aligned rectangle between the two given diagonally opposite
+    /// corners lies inside the polygon.
+    pub fn contains_rect(&self, a: Vec2, b: Vec2) -> bool {
+        let (min, max) = (a.min(b), a.max(b));
+        if min.x == max.x || min.y == max.y {
+            return self.contains_segment(min, max);
+        }
+
+        // The boundary never enters the rectangle, so its inside lies wholly inside or wholly
+        // outside the polygon and checking the center is enough.
+        let corners = [min, vec2(max.x, min.y), max, vec2(min.x, max.y)];
+        let enters = self.edges().any(|(p, q)| {
+            let separated_x = p.x.max(q.x) <= min.x || p.x.min(q.x) >= max.x;
+            let separated_y = p.y.max(q.y) <= min.y || p.y.min(q.y) >= max.y;
+            let sides = corners.map(|c| (q - p).cross(c - p));
+            let separated_line = sides.iter().all(|&s| s >= 0) || sides.iter().all(|&s| s <= 0);
+            !(separated_x || separated_y || separated_line)
+        });
+        !enters && self.contains_scaled(min + max, 2)
+    }
+
+    /// Whether the axis-aligned segment between the two points lies inside the polygon.
+    fn contains_segment(&self, a: Vec2, b: Vec2) -> bool {
+        if self.edges().any(|(p, q)| crosses(a, b, p, q)) {
+            return false;
+        }
+
+        // Between two consecutive points where the segment touches a vertex, it is either
+        // wholly inside or wholly outside, so checking the midpoints is enough.
+        let mut stops: Vec<Vec2> = self
+            .vertices
+            .iter()
+            .copied()
+            .filter(|&v| on_segment(v, a, b))
+            .chain([a, b])
+            .collect();
+        stops.sort_by_key(|&v| (v.x, v.y));
+        stops.dedup();
+        self.contains(a)
+            && stops
+                .windows(2)
+                .all(|w| self.contains_scaled(w[0] + w[1], 2))
+    }
+
+    /// Whether the point lies inside the polygon with its vertices multiplied by `scale`.
+    fn contains_scaled(&self, p: Vec2, scale: isize) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (a, b) = (a * scale, b * scale);
+            if on_segment(p, a, b) {
+                return true;
+            }
+            if (a.y > p.y) != (b.y > p.y) {
+                let side = (b - a).cross(p - a);
+                if (side > 0) == (b.y > a.y) {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+impl FromIterator<Vec2> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Vec2>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+fn on_segment(p: Vec2, a: Vec2, b: Vec2) -> bool {
+    (b - a).cross(p - a) == 0 && p.min(a.min(b)) == a.min(b) && p.max(a.max(b)) == a.max(b)
+}
+
+/// Whether the two segments cross at a single point inside both of them.
+fn crosses(a: Vec2, b: Vec2, p: Vec2, q: Vec2) -> bool {
+    let side = |from: Vec2, to: Vec2, c: Vec2| (to - from).cross(c - from).signum();
+    let (ab_p, ab_q) = (side(a, b, p), side(a, b, q));
+    let (pq_a, pq_b) = (side(p, q, a), side(p, q, b));
+    ab_p * ab_q < 0 && pq_a * pq_b < 0
+}
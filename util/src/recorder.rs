@@ -0,0 +1,333 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use crate::{Glyph, Grid, Rgb};
+
+/// Records snapshots of a grid so that a simulation can be watched afterwards.
+#[derive(Debug, Clone)]
+pub struct Recorder<T> {
+    frames: Vec<Grid<T>>,
+}
+
+impl<T: Clone> Default for Recorder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Recorder<T> {
+    pub fn new() -> Self {
+        Self { frames: vec![] }
+    }
+
+    /// Capture a copy of the grid as the next frame.
+    pub fn record(&mut self, grid: &Grid<T>) {
+        self.frames.push(grid.clone());
+    }
+
+    pub fn frames(&self) -> &[Grid<T>] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Write the frames as a looping animated GIF with the given frame rate.
+    ///
+    /// Each cell becomes a `scale` x `scale` square colored by the palette, which may use
+    /// at most 256 distinct colors.
+    pub fn write_gif(
+        &self,
+        mut out: impl Write,
+        fps: u32,
+        scale: usize,
+        palette: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        let images: Vec<_> = self
+            .frames
+            .iter()
+            .map(|grid| grid.to_image(scale, &palette))
+            .collect();
+        let Some(first) = images.first() else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "No frames"));
+        };
+        if images
+            .iter()
+            .any(|image| (image.width(), image.height()) != (first.width(), first.height()))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "GIF frames must all have the same size",
+            ));
+        }
+        let (Ok(width), Ok(height)) = (u16::try_from(first.width()), u16::try_from(first.height()))
+        else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "GIF dimensions are limited to 65535 pixels",
+            ));
+        };
+
+        let mut colors: Vec<Rgb> = vec![];
+        let mut lookup = HashMap::new();
+        let mut frames = vec![];
+        for image in &images {
+            let mut indices = Vec::with_capacity(image.pixels().len());
+            for &color in image.pixels() {
+                let index = *lookup.entry(color).or_insert_with(|| {
+                    colors.push(color);
+                    colors.len() - 1
+                });
+                indices.push(index as u8);
+            }
+            frames.push(indices);
+        }
+        if colors.len() > 256 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "GIF palette is limited to 256 colors",
+            ));
+        }
+
+        let table_bits = colors.len().next_power_of_two().trailing_zeros().max(1);
+        colors.resize(1 << table_bits, [0, 0, 0]);
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        out.write_all(&[0xf0 | (table_bits - 1) as u8, 0, 0])?;
+        out.write_all(colors.as_flattened())?;
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        let delay = (100 / fps.max(1)) as u16;
+        let min_code_size = table_bits.max(2) as u8;
+        for indices in frames {
+            out.write_all(&[0x21, 0xf9, 4, 0])?;
+            out.write_all(&delay.to_le_bytes())?;
+            out.write_all(&[0, 0, 0x2c, 0, 0, 0, 0])?;
+            out.write_all(&width.to_le_bytes())?;
+            out.write_all(&height.to_le_bytes())?;
+            out.write_all(&[0, min_code_size])?;
+            for block in lzw_encode(&indices, min_code_size).chunks(255) {
+                out.write_all(&[block.len() as u8])?;
+                out.write_all(block)?;
+            }
+            out.write_all(&[0])?;
+        }
+        out.write_all(&[0x3b])
+    }
+
+    /// Save the frames as an animated GIF file. See [`Self::write_gif`].
+    pub fn save_gif(
+        &self,
+        path: impl AsRef<Path>,
+        fps: u32,
+        scale: usize,
+        palette: impl Fn(&T) -> Rgb,
+    ) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_gif(&mut out, fps, scale, palette)?;
+        out.flush()
+    }
+
+    /// Play the frames back in the terminal at the given frame rate.
+    pub fn play(&self, fps: u32) -> io::Result<()>
+    where
+        T: Glyph,
+    {
+        self.play_to(io::stdout().lock(), fps)
+    }
+
+    /// Play the frames back to the given terminal output at the given frame rate.
+    pub fn play_to(&self, mut out: impl Write, fps: u32) -> io::Result<()>
+    where
+        T: Glyph,
+    {
+        let delay = Duration::from_secs(1) / fps.max(1);
+        write!(out, "\x1b[2J")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            write!(out, "\x1b[H{}", frame.render())?;
+            writeln!(out, "Frame {}/{}", i + 1, self.frames.len())?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+}
+
+/// Compress color indices with the variable code width LZW used by GIF.
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut bits = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut code_size = min_code_size as u32 + 1;
+    let mut next = end + 1;
+
+    bits.write(clear, code_size);
+    let Some((&first, rest)) = indices.split_first() else {
+        bits.write(end, code_size);
+        return bits.finish();
+    };
+
+    let mut prefix = first as u16;
+    for &k in rest {
+        if let Some(&code) = table.get(&(prefix, k)) {
+            prefix = code;
+            continue;
+        }
+
+        bits.write(prefix, code_size);
+        if next == 4096 {
+            bits.write(clear, code_size);
+            table.clear();
+            code_size = min_code_size as u32 + 1;
+            next = end + 1;
+        } else {
+            if next >= 1 << code_size {
+                code_size += 1;
+            }
+            table.insert((prefix, k), next);
+            next += 1;
+        }
+        prefix = k as u16;
+    }
+    bits.write(prefix, code_size);
+    // The decoder adds one more entry after reading the last code, and may widen before the end.
+    if next >= 1 << code_size && code_size < 12 {
+        code_size += 1;
+    }
+    bits.write(end, code_size);
+    bits.finish()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.len;
+        self.len += size;
+        while self.len >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A strict GIF LZW decoder, which fails on codes that are out of place or the wrong width.
+    fn lzw_decode(data: &[u8], min_code_size: u8) -> Result<Vec<u8>, String> {
+        let clear = 1usize << min_code_size;
+        let end = clear + 1;
+        let mut pos = 0;
+        let mut read = |size: u32| -> Result<usize, String> {
+            let mut code = 0;
+            for i in 0..size as usize {
+                let byte = data.get((pos + i) / 8).ok_or("Ran out of data")?;
+                code |= ((*byte as usize >> ((pos + i) % 8)) & 1) << i;
+            }
+            pos += size as usize;
+            Ok(code)
+        };
+
+        let mut out = vec![];
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut code_size = min_code_size as u32 + 1;
+        let mut prev: Option<usize> = None;
+        if read(code_size)? != clear {
+            return Err("Missing clear code".to_string());
+        }
+        loop {
+            let code = read(code_size)?;
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                code_size = min_code_size as u32 + 1;
+                prev = None;
+                continue;
+            }
+            if code == end {
+                return Ok(out);
+            }
+            if table.is_empty() {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+            }
+            let entry = match (code < table.len(), prev) {
+                (true, _) => table[code].clone(),
+                (false, Some(p)) if code == table.len() => {
+                    let mut entry = table[p].clone();
+                    entry.push(entry[0]);
+                    entry
+                }
+                _ => return Err(format!("Unexpected code {code}")),
+            };
+            out.extend_from_slice(&entry);
+            if let Some(p) = prev
+                && table.len() < 4096
+            {
+                let mut new = table[p].clone();
+                new.push(entry[0]);
+                table.push(new);
+                if table.len() == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            prev = Some(code);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = |n: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % n
+        };
+
+        for round in 0..2000 {
+            let min_code_size = 2 + random(7) as u8;
+            let colors = 1 + random(1 << min_code_size);
+            // Mostly short streams, with some long enough to fill the table and clear it.
+            let len = if round % 50 == 0 { 30_000 } else { random(300) };
+            let indices: Vec<u8> = (0..len).map(|_| random(colors) as u8).collect();
+
+            let encoded = lzw_encode(&indices, min_code_size);
+            assert_eq!(
+                lzw_decode(&encoded, min_code_size),
+                Ok(indices),
+                "round {round}, min code size {min_code_size}"
+            );
+        }
+    }
+}
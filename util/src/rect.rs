@@ -0,0 +1,282 @@
+use crate::{Vec2, Vec3, vec2, vec3};
+
+/// An axis-aligned rectangle of grid cells. Both corners are included, so a rectangle is
+/// never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl Rect {
+    /// Create the rectangle between two diagonally opposite cells, both included.
+    pub fn new(a: Vec2, b: Vec2) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Create the rectangle from `min` up to but not including `max`.
+    pub fn exclusive(min: Vec2, max: Vec2) -> Self {
+        assert!(min.x < max.x && min.y < max.y, "Rectangle is empty");
+        Self { min, max: max - 1 }
+    }
+
+    /// Create the smallest rectangle containing all the given points.
+    pub fn bounding(points: impl IntoIterator<Item = Vec2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |rect, p| {
+            rect.union(&Self::new(p, p))
+        }))
+    }
+
+    pub const fn min(&self) -> Vec2 {
+        self.min
+    }
+
+    pub const fn max(&self) -> Vec2 {
+        self.max
+    }
+
+    pub const fn width(&self) -> isize {
+        self.max.x - self.min.x + 1
+    }
+
+    pub const fn height(&self) -> isize {
+        self.max.y - self.min.y + 1
+    }
+
+    /// The number of cells.
+    pub const fn area(&self) -> isize {
+        self.width() * self.height()
+    }
+
+    /// The corners in clockwise order, starting at the top left.
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.min,
+            vec2(self.max.x, self.min.y),
+            self.max,
+            vec2(self.min.x, self.max.y),
+        ]
+    }
+
+    pub fn contains(&self, p: Vec2) -> bool {
+        p.min(self.min) == self.min && p.max(self.max) == self.max
+    }
+
+    /// Check if the other rectangle lies wholly inside this one.
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The cells in both rectangles, if there are any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (min, max) = (self.min.max(other.min), self.max.min(other.max));
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    /// The smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Split the cells of this rectangle that are not in the other one into at most four
+    /// disjoint rectangles.
+    pub fn split(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut out = vec![];
+        if self.min.y < cut.min.y {
+            out.push(Self::new(self.min, vec2(self.max.x, cut.min.y - 1)));
+        }
+        if cut.max.y < self.max.y {
+            out.push(Self::new(vec2(self.min.x, cut.max.y + 1), self.max));
+        }
+        if self.min.x < cut.min.x {
+            out.push(Self::new(
+                vec2(self.min.x, cut.min.y),
+                vec2(cut.min.x - 1, cut.max.y),
+            ));
+        }
+        if cut.max.x < self.max.x {
+            out.push(Self::new(
+                vec2(cut.max.x + 1, cut.min.y),
+                vec2(self.max.x, cut.max.y),
+            ));
+        }
+        out
+    }
+
+    /// Iterate over the cells row by row.
+    pub fn points(&self) -> impl Iterator<Item = Vec2> + use<> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| vec2(x, y)))
+    }
+
+    /// Iterate over the cells on the border clockwise, starting at the top left corner.
+    /// Each cell is visited once.
+    pub fn border(&self) -> impl Iterator<Item = Vec2> + use<> {
+        let Self { min, max } = *self;
+        let top = (min.x..=max.x).map(move |x| vec2(x, min.y));
+        let right = (min.y + 1..=max.y).map(move |y| vec2(max.x, y));
+        let bottom = (min.x..max.x)
+            .rev()
+            .map(move |x| vec2(x, max.y))
+            .filter(move |_| max.y > min.y);
+        let left = (min.y + 1..max.y)
+            .rev()
+            .map(move |y| vec2(min.x, y))
+            .filter(move |_| max.x > min.x);
+        top.chain(right).chain(bottom).chain(left)
+    }
+}
+
+/// An axis-aligned box of cells. Both corners are included, so a cuboid is never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    min: Vec3,
+    max: Vec3,
+}
+
+impl Cuboid {
+    /// Create the cuboid between two diagonally opposite cells, both included.
+    pub fn new(a: Vec3, b: Vec3) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Create the cuboid from `min` up to but not including `max`.
+    pub fn exclusive(min: Vec3, max: Vec3) -> Self {
+        assert!(
+            min.x < max.x && min.y < max.y && min.z < max.z,
+            "Cuboid is empty"
+        );
+        Self { min, max: max - 1 }
+    }
+
+    /// Create the smallest cuboid containing all the given points.
+    pub fn bounding(points: impl IntoIterator<Item = Vec3>) -> Option<Self> {
+        Vec3::bounds(points).map(|(min, max)| Self { min, max })
+    }
+
+    pub const fn min(&self) -> Vec3 {
+        self.min
+    }
+
+    pub const fn max(&self) -> Vec3 {
+        self.max
+    }
+
+    /// The number of cells along each axis.
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min + 1
+    }
+
+    /// The number of cells.
+    pub fn volume(&self) -> isize {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, p: Vec3) -> bool {
+        p.min(self.min) == self.min && p.max(self.max) == self.max
+    }
+
+    /// Check if the other cuboid lies wholly inside this one.
+    pub fn contains_cuboid(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The cells in both cuboids, if there are any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (min, max) = (self.min.max(other.min), self.max.min(other.max));
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    /// The smallest cuboid containing both cuboids.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Split the cells of this cuboid that are not in the other one into at most six
+    /// disjoint cuboids.
+    pub fn split(&self, other: &Self) -> Vec<Self> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let (min, max) = (self.min, self.max);
+        let mut out = vec![];
+        if min.z < cut.min.z {
+            out.push(Self::new(min, vec3(max.x, max.y, cut.min.z - 1)));
+        }
+        if cut.max.z < max.z {
+            out.push(Self::new(vec3(min.x, min.y, cut.max.z + 1), max));
+        }
+        if min.y < cut.min.y {
+            out.push(Self::new(
+                vec3(min.x, min.y, cut.min.z),
+                vec3(max.x, cut.min.y - 1, cut.max.z),
+            ));
+        }
+        if cut.max.y < max.y {
+            out.push(Self::new(
+                vec3(min.x, cut.max.y + 1, cut.min.z),
+                vec3(max.x, max.y, cut.max.z),
+            ));
+        }
+        if min.x < cut.min.x {
+            out.push(Self::new(
+                vec3(min.x, cut.min.y, cut.min.z),
+                vec3(cut.min.x - 1, cut.max.y, cut.max.z),
+            ));
+        }
+        if cut.max.x < max.x {
+            out.push(Self::new(
+                vec3(cut.max.x + 1, cut.min.y, cut.min.z),
+                vec3(max.x, cut.max.y, cut.max.z),
+            ));
+        }
+        out
+    }
+
+    /// Iterate over the cells layer by layer, then row by row.
+    pub fn points(&self) -> impl Iterator<Item = Vec3> + use<> {
+        let Self { min, max } = *self;
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| vec3(x, y, z)))
+        })
+    }
+
+    /// Iterate over the cells on the surface, in the same order as [`Self::points`].
+    pub fn surface(&self) -> impl Iterator<Item = Vec3> + use<> {
+        let Self { min, max } = *self;
+        self.points().filter(move |p| {
+            p.x == min.x
+                || p.x == max.x
+                || p.y == min.y
+                || p.y == max.y
+                || p.z == min.z
+                || p.z == max.z
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use crate::{Grid, Vec2, vec2};
+
+/// A value that can be drawn as a single character.
+pub trait Glyph {
+    fn glyph(&self) -> char;
+}
+
+impl Glyph for u8 {
+    fn glyph(&self) -> char {
+        *self as char
+    }
+}
+
+impl Glyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+impl Glyph for bool {
+    fn glyph(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+impl<T: Glyph> Glyph for &T {
+    fn glyph(&self) -> char {
+        (*self).glyph()
+    }
+}
+
+/// A compact text rendering of a grid with one character per cell.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    overlay: Grid<Option<char>>,
+    rulers: bool,
+    min: Vec2,
+    max: Vec2,
+}
+
+impl<T: Glyph> Grid<T> {
+    /// Render the grid with one character per cell.
+    pub fn render(&self) -> Render<'_, T> {
+        Render {
+            grid: self,
+            overlay: self.with_fill(None),
+            rulers: false,
+            min: vec2(0, 0),
+            max: vec2(self.width() as isize - 1, self.height() as isize - 1),
+        }
+    }
+}
+
+impl<T> Render<'_, T> {
+    /// Draw the given points with the given character instead of their cell value.
+    /// Later overlays are drawn on top of earlier ones.
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Vec2>, glyph: char) -> Self {
+        for p in points {
+            self.overlay.replace(p, Some(glyph));
+        }
+        self
+    }
+
+    /// Label the columns above the grid and the rows to its left.
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    /// Only draw the rectangle between the two given diagonally opposite points.
+    pub fn crop(mut self, a: Vec2, b: Vec2) -> Self {
+        self.min = vec2(a.x.min(b.x).max(0), a.y.min(b.y).max(0));
+        self.max = vec2(
+            a.x.max(b.x).min(self.grid.width() as isize - 1),
+            a.y.max(b.y).min(self.grid.height() as isize - 1),
+        );
+        self
+    }
+}
+
+impl<T: Glyph> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label_width = self.max.y.max(0).to_string().len();
+        if self.rulers {
+            let digits = self.max.x.max(0).to_string().len();
+            for d in (0..digits).rev() {
+                write!(f, "{:label_width$} ", "")?;
+                for x in self.min.x..=self.max.x {
+                    let digit = x / 10_isize.pow(d as u32);
+                    if digit == 0 && d > 0 {
+                        write!(f, " ")?;
+                    } else {
+                        write!(f, "{}", digit % 10)?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
+        for y in self.min.y..=self.max.y {
+            if self.rulers {
+                write!(f, "{y:>label_width$} ")?;
+            }
+            for x in self.min.x..=self.max.x {
+                let p = vec2(x, y);
+                let c = self.overlay[p].unwrap_or_else(|| self.grid[p].glyph());
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
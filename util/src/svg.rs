@@ -0,0 +1,306 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Write as _},
+    fs,
+    hash::Hash,
+    io,
+    path::Path,
+};
+
+use crate::{BiGraph, DiGraph, Grid, Rgb, Vec2, vec2};
+
+/// A vector drawing in grid coordinates, where every cell is a unit square.
+///
+/// Points of paths, polygons and graphs are drawn at the centers of their cells.
+#[derive(Debug, Clone)]
+pub struct Svg {
+    min: Vec2,
+    max: Vec2,
+    scale: f64,
+    body: String,
+    arrows: bool,
+}
+
+impl Svg {
+    /// Create a drawing covering the rectangle between the two given diagonally opposite cells.
+    pub fn new(a: Vec2, b: Vec2) -> Self {
+        Self {
+            min: vec2(a.x.min(b.x), a.y.min(b.y)),
+            max: vec2(a.x.max(b.x), a.y.max(b.y)),
+            scale: 10.0,
+            body: String::new(),
+            arrows: false,
+        }
+    }
+
+    /// Create a drawing covering the given points.
+    pub fn covering(points: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut points = points.into_iter();
+        let first = points.next().expect("No points to cover");
+        let (min, max) = points.fold((first, first), |(min, max), p| {
+            (
+                vec2(min.x.min(p.x), min.y.min(p.y)),
+                vec2(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        Self::new(min, max)
+    }
+
+    /// Create a drawing the size of the given grid.
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        Self::new(
+            vec2(0, 0),
+            vec2(grid.width() as isize - 1, grid.height() as isize - 1),
+        )
+    }
+
+    /// Set the size of a cell in the output, in pixels.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Draw every cell of the grid as a square colored by the palette. Cells without a color are skipped.
+    pub fn grid<T>(mut self, grid: &Grid<T>, palette: impl Fn(&T) -> Option<Rgb>) -> Self {
+        for p in grid.points() {
+            if let Some(color) = palette(&grid[p]) {
+                let _ = writeln!(
+                    self.body,
+                    r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#,
+                    p.x,
+                    p.y,
+                    hex(color)
+                );
+            }
+        }
+        self
+    }
+
+    /// Draw lines through the given points.
+    pub fn polyline(mut self, points: impl IntoIterator<Item = Vec2>, color: Rgb) -> Self {
+        let _ = writeln!(
+            self.body,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+            coords(points),
+            hex(color),
+            self.line_width()
+        );
+        self
+    }
+
+    /// Draw the outline of the polygon with the given vertices.
+    pub fn polygon(mut self, points: impl IntoIterator<Item = Vec2>, color: Rgb) -> Self {
+        let _ = writeln!(
+            self.body,
+            r#"<polygon points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round"/>"#,
+            coords(points),
+            hex(color),
+            self.line_width()
+        );
+        self
+    }
+
+    /// Draw a dot at each of the given points.
+    pub fn points(mut self, points: impl IntoIterator<Item = Vec2>, color: Rgb) -> Self {
+        for p in points {
+            self.circle(p, color);
+        }
+        self
+    }
+
+    /// Draw an undirected graph, with each vertex placed at the given position and labelled
+    /// with the given text. Empty labels are left out.
+    pub fn graph<T>(
+        mut self,
+        graph: &BiGraph<T>,
+        position: impl Fn(T) -> Vec2,
+        label: impl Fn(T) -> String,
+        color: Rgb,
+    ) -> Self
+    where
+        T: Hash + Eq + Copy,
+    {
+        let mut drawn = HashSet::new();
+        for a in graph.vertices() {
+            for b in graph.neighbors(a) {
+                if drawn.insert((b, a)) {
+                    drawn.insert((a, b));
+                    self.line(position(a), position(b), color, "");
+                }
+            }
+        }
+        self.vertices(graph.vertices(), position, label, color)
+    }
+
+    /// Draw a directed graph with arrows, with each vertex placed at the given position and
+    /// labelled with the given text. Empty labels are left out.
+    pub fn digraph<T>(
+        mut self,
+        graph: &DiGraph<T>,
+        position: impl Fn(T) -> Vec2,
+        label: impl Fn(T) -> String,
+        color: Rgb,
+    ) -> Self
+    where
+        T: Hash + Eq + Copy,
+    {
+        self.arrows = true;
+        let mut vertices = HashSet::new();
+        for a in graph.vertices() {
+            vertices.insert(a);
+            for b in graph.neighbors(a) {
+                vertices.insert(b);
+                self.line(
+                    position(a),
+                    position(b),
+                    color,
+                    r#" marker-end="url(#arrow)""#,
+                );
+            }
+        }
+        self.vertices(vertices, position, label, color)
+    }
+
+    /// Write the drawing to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    fn vertices<T: Copy>(
+        mut self,
+        vertices: impl IntoIterator<Item = T>,
+        position: impl Fn(T) -> Vec2,
+        label: impl Fn(T) -> String,
+        color: Rgb,
+    ) -> Self {
+        for v in vertices {
+            let (p, label) = (position(v), label(v));
+            self.circle(p, color);
+            if label.is_empty() {
+                continue;
+            }
+            let _ = writeln!(
+                self.body,
+                r#"<text x="{}" y="{}" font-size="0.8" text-anchor="middle">{}</text>"#,
+                p.x as f64 + 0.5,
+                p.y as f64 - 0.2,
+                escape(&label)
+            );
+        }
+        self
+    }
+
+    fn line(&mut self, a: Vec2, b: Vec2, color: Rgb, extra: &str) {
+        let _ = writeln!(
+            self.body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"{extra}/>"#,
+            a.x as f64 + 0.5,
+            a.y as f64 + 0.5,
+            b.x as f64 + 0.5,
+            b.y as f64 + 0.5,
+            hex(color),
+            self.line_width()
+        );
+    }
+
+    fn circle(&mut self, p: Vec2, color: Rgb) {
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{}" cy="{}" r="0.3" fill="{}"/>"#,
+            p.x as f64 + 0.5,
+            p.y as f64 + 0.5,
+            hex(color)
+        );
+    }
+
+    /// Line width in cells: a fifth of a cell, but at least one pixel.
+    fn line_width(&self) -> f64 {
+        (1.0 / self.scale).max(0.2)
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = (self.max.x - self.min.x + 1) as f64;
+        let height = (self.max.y - self.min.y + 1) as f64;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            width * self.scale,
+            height * self.scale,
+            self.min.x,
+            self.min.y,
+            width,
+            height
+        )?;
+        if self.arrows {
+            writeln!(
+                f,
+                r#"<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="6" markerHeight="6" orient="auto-start-reverse"><path d="M 0 0 L 10 5 L 0 10 z"/></marker></defs>"#
+            )?;
+        }
+        write!(f, "{}", self.body)?;
+        writeln!(f, "</svg>")
+    }
+}
+
+fn hex([r, g, b]: Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn coords(points: impl IntoIterator<Item = Vec2>) -> String {
+    points
+        .into_iter()
+        .map(|p| format!("{},{}", p.x as f64 + 0.5, p.y as f64 + 0.5))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_grid_path_and_graph() {
+        let grid = Grid::from_bytes("#.\n.#");
+        let mut graph = BiGraph::new();
+        graph.add_edge(vec2(0, 0), vec2(1, 1));
+        let svg = Svg::new(vec2(-1, 0), vec2(1, 2))
+            .grid(&grid, |&c| (c == b'#').then_some([255, 0, 0]))
+            .polyline([vec2(0, 0), vec2(1, 0)], [0, 0, 255])
+            .graph(&graph, |v| v, |v| format!("{v:?}"), [0, 255, 0])
+            .to_string();
+
+        let lines: Vec<_> = svg.lines().collect();
+        assert_eq!(
+            lines[0],
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="30" viewBox="-1 0 3 3">"#
+        );
+        assert_eq!(
+            &lines[1..4],
+            [
+                r##"<rect x="0" y="0" width="1" height="1" fill="#ff0000"/>"##,
+                r##"<rect x="1" y="1" width="1" height="1" fill="#ff0000"/>"##,
+                r##"<polyline points="0.5,0.5 1.5,0.5" fill="none" stroke="#0000ff" stroke-width="0.2" stroke-linejoin="round"/>"##,
+            ]
+        );
+        let line = lines
+            .iter()
+            .filter(|l| l.starts_with("<line"))
+            .collect::<Vec<_>>();
+        assert_eq!(line.len(), 1);
+        assert!(
+            line[0].starts_with(r#"<line x1="0.5" y1="0.5" x2="1.5" y2="1.5""#)
+                || line[0].starts_with(r#"<line x1="1.5" y1="1.5" x2="0.5" y2="0.5""#)
+        );
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert_eq!(svg.matches("<text").count(), 2);
+        assert_eq!(lines.last(), Some(&"</svg>"));
+    }
+}
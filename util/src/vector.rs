@@ -1,4 +1,10 @@
-use crate::Dir;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{Dir, Rect};
 
 pub const UP: Vec2 = vec2(0, -1);
 pub const DOWN: Vec2 = vec2(0, 1);
@@ -10,13 +16,78 @@ pub const DOWN_LEFT: Vec2 = vec2(-1, 1);
 pub const DOWN_RIGHT: Vec2 = vec2(1, 1);
 pub const ZERO: Vec2 = vec2(0, 0);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vec2 {
-    pub x: isize,
-    pub y: isize,
+/// A numeric type that vectors can be built from.
+pub trait Num:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn abs(self) -> Self;
+
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    /// -1, 0 or 1 depending on the sign. Unlike `f64::signum`, zero maps to zero.
+    fn signum(self) -> Self {
+        if self > Self::ZERO {
+            Self::ONE
+        } else if self < Self::ZERO {
+            Self::ZERO - Self::ONE
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+macro_rules! impl_num {
+    ($abs:ident => $($t:ty),*) => {
+        $(impl Num for $t {
+            const ZERO: Self = 0 as $t;
+            const ONE: Self = 1 as $t;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn abs(self) -> Self {
+                impl_num!(@$abs self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+    (@signed $v:ident) => { $v.abs() };
+    (@unsigned $v:ident) => { $v };
+}
+
+impl_num!(signed => i8, i16, i32, i64, i128, isize, f32, f64);
+impl_num!(unsigned => u8, u16, u32, u64, u128, usize);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec2<T = isize> {
+    pub x: T,
+    pub y: T,
 }
 
-pub const fn vec2(x: isize, y: isize) -> Vec2 {
+pub const fn vec2<T>(x: T, y: T) -> Vec2<T> {
     Vec2 { x, y }
 }
 
@@ -37,23 +108,149 @@ impl Vec2 {
         .map(move |dir| self + dir)
     }
 
-    pub fn manhattan_dist(self, other: Vec2) -> isize {
-        (self - other).manhattan()
+    /// Find every vector in the text by taking its integers two at a time.
+    /// Leftover integers at the end are ignored.
+    ///
+    /// For example, `p=0,4 v=3,-3` gives `(0, 4)` and `(3, -3)`.
+    pub fn extract_all(s: &str) -> Vec<Self> {
+        extract_ints(s)
+            .chunks_exact(2)
+            .map(|c| vec2(c[0], c[1]))
+            .collect()
     }
 
-    pub fn manhattan(&self) -> isize {
-        self.x.abs() + self.y.abs()
+    /// The smallest step in the same direction, with the components divided by their gcd.
+    pub fn reduced(self) -> Self {
+        let gcd = gcd(self.x.unsigned_abs(), self.y.unsigned_abs()) as isize;
+        if gcd == 0 { self } else { self / gcd }
     }
 
     /// Iterate over the points in the rectangle defined by the two given diagonal opposite points.
     pub fn rect_points(a: Self, b: Self) -> impl Iterator<Item = Vec2> {
-        let (x1, x2) = (a.x.min(b.x), a.x.max(b.x));
-        let (y1, y2) = (a.y.min(b.y), a.y.max(b.y));
-        (y1..=y2).flat_map(move |y| (x1..=x2).map(move |x| vec2(x, y)))
+        Rect::new(a, b).points()
+    }
+}
+
+impl<T: Num> Vec2<T> {
+    pub fn manhattan_dist(self, other: Self) -> T {
+        (self - other).manhattan()
+    }
+
+    pub fn manhattan(&self) -> T {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Distance when moving diagonally costs the same as moving straight.
+    pub fn chebyshev_dist(self, other: Self) -> T {
+        (self - other).chebyshev()
+    }
+
+    pub fn chebyshev(&self) -> T {
+        let (x, y) = (self.x.abs(), self.y.abs());
+        if x > y { x } else { y }
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3D cross product, positive if `other` is clockwise from `self`
+    /// when y points down.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn abs(self) -> Self {
+        vec2(self.x.abs(), self.y.abs())
     }
+
+    /// -1, 0 or 1 for each component depending on its sign.
+    pub fn signum(self) -> Self {
+        vec2(self.x.signum(), self.y.signum())
+    }
+
+    /// Wrap each component into `0..size`, e.g. to wrap a point around the edges of a grid.
+    pub fn rem_euclid(self, size: Self) -> Self {
+        vec2(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    /// Smallest value of each component.
+    pub fn min(self, other: Self) -> Self {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        vec2(min(self.x, other.x), min(self.y, other.y))
+    }
+
+    /// Largest value of each component.
+    pub fn max(self, other: Self) -> Self {
+        let max = |a: T, b: T| if b > a { b } else { a };
+        vec2(max(self.x, other.x), max(self.y, other.y))
+    }
+
+    /// Linear interpolation, giving `self` at 0 and `other` at 1.
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Convert to a vector of another type that can hold every value of this one.
+    pub fn cast<U: From<T>>(self) -> Vec2<U> {
+        vec2(self.x.into(), self.y.into())
+    }
+
+    /// Convert to a vector of another type, if both components fit.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vec2<U>> {
+        Some(vec2(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    pub fn as_f64(self) -> Vec2<f64> {
+        vec2(self.x.to_f64(), self.y.to_f64())
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Vec2<T> {
+    /// Rotate a quarter turn clockwise around the origin when y points down, like [`Dir::turn_right`].
+    pub fn rotate_right(self) -> Self {
+        vec2(-self.y, self.x)
+    }
+
+    /// Rotate a quarter turn counter-clockwise around the origin when y points down, like [`Dir::turn_left`].
+    pub fn rotate_left(self) -> Self {
+        vec2(self.y, -self.x)
+    }
+
+    /// Rotate a quarter turn clockwise around the given point.
+    pub fn rotate_right_around(self, pivot: Self) -> Self {
+        (self - pivot).rotate_right() + pivot
+    }
+
+    /// Rotate a quarter turn counter-clockwise around the given point.
+    pub fn rotate_left_around(self, pivot: Self) -> Self {
+        (self - pivot).rotate_left() + pivot
+    }
+}
+
+pub(crate) fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
 }
 
-impl std::ops::Add for Vec2 {
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        vec2(x, y)
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        vec2(x, y)
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T: Num> Add for Vec2<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -61,14 +258,14 @@ impl std::ops::Add for Vec2 {
     }
 }
 
-impl std::ops::AddAssign for Vec2 {
+impl<T: Num> AddAssign for Vec2<T> {
     fn add_assign(&mut self, other: Self) {
         self.x += other.x;
         self.y += other.y;
     }
 }
 
-impl std::ops::Sub for Vec2 {
+impl<T: Num> Sub for Vec2<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -76,114 +273,478 @@ impl std::ops::Sub for Vec2 {
     }
 }
 
-impl std::ops::SubAssign for Vec2 {
+impl<T: Num> SubAssign for Vec2<T> {
     fn sub_assign(&mut self, other: Self) {
         self.x -= other.x;
         self.y -= other.y;
     }
 }
 
-impl std::ops::Add<isize> for Vec2 {
+impl<T: Num> Add<T> for Vec2<T> {
     type Output = Self;
 
-    fn add(self, scalar: isize) -> Self {
+    fn add(self, scalar: T) -> Self {
         vec2(self.x + scalar, self.y + scalar)
     }
 }
 
-impl std::ops::AddAssign<isize> for Vec2 {
-    fn add_assign(&mut self, scalar: isize) {
+impl<T: Num> AddAssign<T> for Vec2<T> {
+    fn add_assign(&mut self, scalar: T) {
         self.x += scalar;
         self.y += scalar;
     }
 }
 
-impl std::ops::Sub<isize> for Vec2 {
+impl<T: Num> Sub<T> for Vec2<T> {
     type Output = Self;
 
-    fn sub(self, scalar: isize) -> Self {
+    fn sub(self, scalar: T) -> Self {
         vec2(self.x - scalar, self.y - scalar)
     }
 }
 
-impl std::ops::SubAssign<isize> for Vec2 {
-    fn sub_assign(&mut self, scalar: isize) {
+impl<T: Num> SubAssign<T> for Vec2<T> {
+    fn sub_assign(&mut self, scalar: T) {
         self.x -= scalar;
         self.y -= scalar;
     }
 }
 
-impl std::ops::Mul<isize> for Vec2 {
+impl<T: Num> Mul<T> for Vec2<T> {
     type Output = Self;
 
-    fn mul(self, scalar: isize) -> Self {
+    fn mul(self, scalar: T) -> Self {
         vec2(self.x * scalar, self.y * scalar)
     }
 }
 
-impl std::ops::MulAssign<isize> for Vec2 {
-    fn mul_assign(&mut self, scalar: isize) {
+impl<T: Num> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, scalar: T) {
         self.x *= scalar;
         self.y *= scalar;
     }
 }
 
-impl std::ops::Div<isize> for Vec2 {
+impl<T: Num> Div<T> for Vec2<T> {
     type Output = Self;
 
-    fn div(self, scalar: isize) -> Self {
+    fn div(self, scalar: T) -> Self {
         vec2(self.x / scalar, self.y / scalar)
     }
 }
 
-impl std::ops::DivAssign<isize> for Vec2 {
-    fn div_assign(&mut self, scalar: isize) {
+impl<T: Num> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, scalar: T) {
         self.x /= scalar;
         self.y /= scalar;
     }
 }
 
-pub fn range(start: Vec2, end: Vec2) -> impl Iterator<Item = Vec2> {
-    if start.x != end.x && start.y != end.y {
-        panic!("Range must be horizontal or vertical");
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        vec2(-self.x, -self.y)
     }
-    let step = vec2((end.x - start.x).signum(), (end.y - start.y).signum());
-    let mut current = start;
-    std::iter::from_fn(move || {
-        if current == end {
-            return None;
-        }
-        let next = current + step;
-        current = next;
-        Some(current)
-    })
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vec3 {
-    pub x: isize,
-    pub y: isize,
-    pub z: isize,
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec3<T = isize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-pub const fn vec3(x: isize, y: isize, z: isize) -> Vec3 {
+pub const fn vec3<T>(x: T, y: T, z: T) -> Vec3<T> {
     Vec3 { x, y, z }
 }
 
+impl<T: Num> Vec3<T> {
+    /// Convert to a vector of another type that can hold every value of this one.
+    pub fn cast<U: From<T>>(self) -> Vec3<U> {
+        vec3(self.x.into(), self.y.into(), self.z.into())
+    }
+
+    /// Convert to a vector of another type, if all components fit.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vec3<U>> {
+        Some(vec3(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+            self.z.try_into().ok()?,
+        ))
+    }
+
+    pub fn as_f64(self) -> Vec3<f64> {
+        vec3(self.x.to_f64(), self.y.to_f64(), self.z.to_f64())
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        vec3(x, y, z)
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        vec3(x, y, z)
+    }
+}
+
 impl Vec3 {
+    /// Parse `x,y,z`, panicking if there aren't exactly three integer components.
     pub fn parse_csv(input: &str) -> Self {
-        let mut list = [0, 0, 0];
-        for (i, x) in input.split(',').map(|x| x.parse().unwrap()).enumerate() {
-            list[i] = x;
+        input.parse().unwrap()
+    }
+
+    /// Find every vector in the text by taking its integers three at a time.
+    /// Leftover integers at the end are ignored.
+    ///
+    /// For example, `pos=<1,-2,3>, r=4` gives only `(1, -2, 3)`.
+    pub fn extract_all(s: &str) -> Vec<Self> {
+        extract_ints(s)
+            .chunks_exact(3)
+            .map(|c| vec3(c[0], c[1], c[2]))
+            .collect()
+    }
+
+    /// Iterate over the 6 points sharing a face with this one.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        [
+            vec3(1, 0, 0),
+            vec3(-1, 0, 0),
+            vec3(0, 1, 0),
+            vec3(0, -1, 0),
+            vec3(0, 0, 1),
+            vec3(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+
+    /// Iterate over the 26 points surrounding this one.
+    pub fn neighbors_all(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| vec3(x, y, z))))
+            .filter(|&d| d != vec3(0, 0, 0))
+            .map(move |d| self + d)
+    }
+}
+
+impl<T: Num> Vec3<T> {
+    pub fn manhattan_dist(self, other: Self) -> T {
+        (self - other).manhattan()
+    }
+
+    pub fn manhattan(&self) -> T {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    /// Square of the straight line distance, which is exact for integers.
+    pub fn euclidean_dist_sq(self, other: Self) -> T {
+        let d = self - other;
+        d.dot(d)
+    }
+
+    /// Straight line distance.
+    pub fn euclidean_dist(self, other: Self) -> f64 {
+        self.euclidean_dist_sq(other).to_f64().sqrt()
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        vec3(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Smallest value of each component.
+    pub fn min(self, other: Self) -> Self {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        vec3(
+            min(self.x, other.x),
+            min(self.y, other.y),
+            min(self.z, other.z),
+        )
+    }
+
+    /// Largest value of each component.
+    pub fn max(self, other: Self) -> Self {
+        let max = |a: T, b: T| if b > a { b } else { a };
+        vec3(
+            max(self.x, other.x),
+            max(self.y, other.y),
+            max(self.z, other.z),
+        )
+    }
+
+    /// The smallest and largest corners of the box containing all the given points.
+    pub fn bounds(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), p| (min.min(p), max.max(p))))
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Vec3<T> {
+    /// Rotate a quarter turn counter-clockwise around the x axis.
+    pub fn rotate_x(self) -> Self {
+        vec3(self.x, -self.z, self.y)
+    }
+
+    /// Rotate a quarter turn counter-clockwise around the y axis.
+    pub fn rotate_y(self) -> Self {
+        vec3(self.z, self.y, -self.x)
+    }
+
+    /// Rotate a quarter turn counter-clockwise around the z axis.
+    pub fn rotate_z(self) -> Self {
+        vec3(-self.y, self.x, self.z)
+    }
+
+    /// All 24 rotations of this vector by multiples of a quarter turn.
+    pub fn rotations(self) -> impl Iterator<Item = Self> {
+        let facings: [fn(Self) -> Self; 6] = [
+            |v| v,
+            |v| v.rotate_z(),
+            |v| v.rotate_z().rotate_z(),
+            |v| v.rotate_z().rotate_z().rotate_z(),
+            |v| v.rotate_y(),
+            |v| v.rotate_y().rotate_y().rotate_y(),
+        ];
+        facings.into_iter().flat_map(move |facing| {
+            std::iter::successors(Some(self), |v| Some(v.rotate_x()))
+                .take(4)
+                .map(facing)
+        })
+    }
+}
+
+impl<T: Num> Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        vec3(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Num> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T: Num> Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        vec3(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Num> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<T: Num> Add<T> for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, scalar: T) -> Self {
+        vec3(self.x + scalar, self.y + scalar, self.z + scalar)
+    }
+}
+
+impl<T: Num> AddAssign<T> for Vec3<T> {
+    fn add_assign(&mut self, scalar: T) {
+        self.x += scalar;
+        self.y += scalar;
+        self.z += scalar;
+    }
+}
+
+impl<T: Num> Sub<T> for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, scalar: T) -> Self {
+        vec3(self.x - scalar, self.y - scalar, self.z - scalar)
+    }
+}
+
+impl<T: Num> SubAssign<T> for Vec3<T> {
+    fn sub_assign(&mut self, scalar: T) {
+        self.x -= scalar;
+        self.y -= scalar;
+        self.z -= scalar;
+    }
+}
+
+impl<T: Num> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        vec3(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<T: Num> MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.x *= scalar;
+        self.y *= scalar;
+        self.z *= scalar;
+    }
+}
+
+impl<T: Num> Div<T> for Vec3<T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        vec3(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
+
+impl<T: Num> DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self, scalar: T) {
+        self.x /= scalar;
+        self.y /= scalar;
+        self.z /= scalar;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        vec3(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Error returned when a vector can't be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVecError(String);
+
+impl fmt::Display for ParseVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid vector: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseVecError {}
+
+impl<T: FromStr> FromStr for Vec2<T> {
+    type Err = ParseVecError;
+
+    /// Parse `x,y`, `(x, y)` or `x=..., y=...`. See [`parse_components`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s, ["x", "y"])?;
+        Ok(vec2(x, y))
+    }
+}
+
+impl<T: FromStr> FromStr for Vec3<T> {
+    type Err = ParseVecError;
+
+    /// Parse `x,y,z`, `(x, y, z)` or `x=..., y=..., z=...`. See [`parse_components`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_components(s, ["x", "y", "z"])?;
+        Ok(vec3(x, y, z))
+    }
+}
+
+/// Parse exactly `N` comma separated components, with optional spaces around them.
+///
+/// The whole list may be wrapped in `()`, `[]` or `<>`, and each component may be prefixed
+/// with its name, as in `x=1`.
+fn parse_components<T: FromStr, const N: usize>(
+    s: &str,
+    names: [&str; N],
+) -> Result<[T; N], ParseVecError> {
+    let err = || ParseVecError(s.to_string());
+    let mut inner = s.trim();
+    for (open, close) in [('(', ')'), ('[', ']'), ('<', '>')] {
+        if let Some(stripped) = inner.strip_prefix(open).and_then(|s| s.strip_suffix(close)) {
+            inner = stripped;
+            break;
         }
-        Self {
-            x: list[0],
-            y: list[1],
-            z: list[2],
+    }
+
+    let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+    if parts.len() != N {
+        return Err(err());
+    }
+    let values = parts
+        .into_iter()
+        .zip(names)
+        .map(|(part, name)| {
+            let value = match part.split_once('=') {
+                Some((key, value)) if key.trim() == name => value.trim(),
+                Some(_) => return Err(err()),
+                None => part,
+            };
+            value.parse().map_err(|_| err())
+        })
+        .collect::<Result<Vec<T>, _>>()?;
+    values.try_into().map_err(|_| err())
+}
+
+/// Find every integer in the text, including negative ones.
+///
+/// A `-` right after a digit is a separator rather than a sign, so `5-7` gives 5 and 7.
+/// Integers that don't fit in an `isize` are skipped.
+fn extract_ints(s: &str) -> Vec<isize> {
+    let mut out = vec![];
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && !(i > 0 && bytes[i - 1].is_ascii_digit());
+        if bytes[i].is_ascii_digit() || negative {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if let Ok(value) = s[start..i].parse() {
+                out.push(value);
+            }
+        } else {
+            i += 1;
         }
     }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_ints_signs() {
+        assert_eq!(extract_ints("p=0,4 v=3,-3"), [0, 4, 3, -3]);
+        assert_eq!(extract_ints("5-7, -2--4"), [5, 7, -2, -4]);
+        assert_eq!(extract_ints("x-1 -"), [-1]);
+    }
 
-    pub fn euclidean_dist(self, other: Vec3) -> isize {
-        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
+    #[test]
+    fn extract_ints_skips_overflow() {
+        assert_eq!(
+            extract_ints("1 99999999999999999999 -9223372036854775808 2"),
+            [1, isize::MIN, 2]
+        );
+        assert_eq!(
+            Vec2::extract_all("1,2 3,99999999999999999999999"),
+            [vec2(1, 2)]
+        );
     }
 }
@@ -0,0 +1,402 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// A directed graph with a cost on every edge.
+pub struct WeightedGraph<T> {
+    edges: HashMap<T, Vec<(T, usize)>>,
+}
+
+impl<T: Hash + Eq + Copy> Default for WeightedGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq + Copy> WeightedGraph<T> {
+    pub fn new() -> Self {
+        Self {
+            edges: HashMap::new(),
+        }
+    }
+
+    pub fn add_edge(&mut self, a: T, b: T, cost: usize) {
+        self.edges.entry(a).or_default().push((b, cost));
+        self.edges.entry(b).or_default();
+    }
+
+    /// Add an edge in both directions with the same cost.
+    pub fn add_undirected_edge(&mut self, a: T, b: T, cost: usize) {
+        self.add_edge(a, b, cost);
+        self.add_edge(b, a, cost);
+    }
+
+    /// Iterate over the vertices reachable in one step, with the cost of each step.
+    pub fn neighbors(&self, v: T) -> impl Iterator<Item = (T, usize)> + '_ {
+        self.edges.get(&v).into_iter().flatten().copied()
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = T> + '_ {
+        self.edges.keys().copied()
+    }
+
+    /// Find the shortest paths from `start` to every reachable vertex. See [`dijkstra`].
+    pub fn dijkstra(&self, start: T) -> ShortestPaths<T> {
+        dijkstra(start, |v| self.neighbors(v))
+    }
+
+    /// Find every shortest path from `start` to every reachable vertex. See [`dijkstra_all`].
+    pub fn dijkstra_all(&self, start: T) -> ShortestPathDag<T> {
+        dijkstra_all(start, |v| self.neighbors(v))
+    }
+
+    /// Find a shortest path from `start` to `goal`. See [`astar`].
+    pub fn astar(
+        &self,
+        start: T,
+        goal: T,
+        heuristic: impl FnMut(T) -> usize,
+    ) -> Option<(usize, Vec<T>)> {
+        astar(start, |v| self.neighbors(v), heuristic, |v| v == goal)
+    }
+
+    /// Find the shortest paths in a graph whose edges all cost 0 or 1. See [`bfs_01`].
+    pub fn bfs_01(&self, start: T) -> ShortestPaths<T> {
+        bfs_01(start, |v| self.neighbors(v))
+    }
+}
+
+impl<T: Hash + Eq + Copy> FromIterator<(T, T, usize)> for WeightedGraph<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T, usize)>>(iter: I) -> Self {
+        let mut graph = WeightedGraph::new();
+        for (a, b, cost) in iter {
+            graph.add_edge(a, b, cost);
+        }
+        graph
+    }
+}
+
+/// The distances from a start state to every reachable state, along with the step taken
+/// into each state so that paths can be rebuilt.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<T> {
+    start: T,
+    dist: HashMap<T, usize>,
+    prev: HashMap<T, T>,
+}
+
+impl<T: Hash + Eq + Copy> ShortestPaths<T> {
+    pub fn distance(&self, state: T) -> Option<usize> {
+        self.dist.get(&state).copied()
+    }
+
+    /// Iterate over every reachable state with its distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (T, usize)> + '_ {
+        self.dist.iter().map(|(&state, &dist)| (state, dist))
+    }
+
+    /// Find the closest state satisfying the predicate.
+    pub fn nearest(&self, filter: impl Fn(T) -> bool) -> Option<(T, usize)> {
+        self.distances()
+            .filter(|&(state, _)| filter(state))
+            .min_by_key(|&(_, dist)| dist)
+    }
+
+    /// The states along a shortest path from the start to the given state, both included.
+    pub fn path_to(&self, target: T) -> Option<Vec<T>> {
+        self.dist.get(&target)?;
+        let mut path = vec![target];
+        let mut current = target;
+        while current != self.start {
+            current = self.prev[&current];
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Find the shortest paths from `start` to every reachable state.
+///
+/// The graph is given implicitly: `neighbors` yields the states reachable from a state in
+/// one step, along with the cost of each step.
+pub fn dijkstra<T, I>(start: T, mut neighbors: impl FnMut(T) -> I) -> ShortestPaths<T>
+where
+    T: Hash + Eq + Copy,
+    I: IntoIterator<Item = (T, usize)>,
+{
+    let mut paths = ShortestPaths {
+        start,
+        dist: HashMap::from([(start, 0)]),
+        prev: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::from([MinCost(0, start)]);
+    while let Some(MinCost(dist, state)) = queue.pop() {
+        if dist > paths.dist[&state] {
+            continue;
+        }
+        for (next, cost) in neighbors(state) {
+            let next_dist = dist + cost;
+            if paths.dist.get(&next).is_none_or(|&d| next_dist < d) {
+                paths.dist.insert(next, next_dist);
+                paths.prev.insert(next, state);
+                queue.push(MinCost(next_dist, next));
+            }
+        }
+    }
+    paths
+}
+
+/// Every shortest path from a start state to every reachable state, stored as the set of
+/// optimal predecessors of each state.
+#[derive(Debug, Clone)]
+pub struct ShortestPathDag<T> {
+    start: T,
+    dist: HashMap<T, usize>,
+    prev: HashMap<T, Vec<T>>,
+}
+
+impl<T: Hash + Eq + Copy> ShortestPathDag<T> {
+    pub fn distance(&self, state: T) -> Option<usize> {
+        self.dist.get(&state).copied()
+    }
+
+    /// Iterate over every reachable state with its distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (T, usize)> + '_ {
+        self.dist.iter().map(|(&state, &dist)| (state, dist))
+    }
+
+    /// Find the closest state satisfying the predicate.
+    pub fn nearest(&self, filter: impl Fn(T) -> bool) -> Option<(T, usize)> {
+        self.distances()
+            .filter(|&(state, _)| filter(state))
+            .min_by_key(|&(_, dist)| dist)
+    }
+
+    /// The states from which the given state is reached on some shortest path.
+    pub fn predecessors(&self, state: T) -> &[T] {
+        self.prev.get(&state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The number of distinct shortest paths from the start to the given state.
+    pub fn count_paths(&self, target: T) -> usize {
+        if !self.dist.contains_key(&target) {
+            return 0;
+        }
+        let mut counts: HashMap<T, usize> = HashMap::new();
+        for state in self.ancestors(target) {
+            let count = if state == self.start {
+                1
+            } else {
+                self.predecessors(state).iter().map(|p| counts[p]).sum()
+            };
+            counts.insert(state, count);
+        }
+        counts[&target]
+    }
+
+    /// Lazily iterate over every shortest path from the start to the given state, both
+    /// included.
+    pub fn paths(&self, target: T) -> impl Iterator<Item = Vec<T>> + '_ {
+        // The path being built backwards from the target, with the index of the next
+        // predecessor to try for each state.
+        let mut stack = if self.dist.contains_key(&target) {
+            vec![(target, 0)]
+        } else {
+            vec![]
+        };
+        std::iter::from_fn(move || {
+            while let Some((state, next)) = stack.last_mut() {
+                if *state == self.start {
+                    let path = stack.iter().rev().map(|&(state, _)| state).collect();
+                    stack.pop();
+                    return Some(path);
+                }
+                match self.predecessors(*state).get(*next) {
+                    Some(&prev) => {
+                        *next += 1;
+                        stack.push((prev, 0));
+                    }
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    /// The states lying on at least one shortest path from the start to any of the targets.
+    pub fn states_on_paths(&self, targets: impl IntoIterator<Item = T>) -> HashSet<T> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<T> = targets
+            .into_iter()
+            .filter(|t| self.dist.contains_key(t))
+            .collect();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state) {
+                stack.extend_from_slice(self.predecessors(state));
+            }
+        }
+        seen
+    }
+
+    /// The states on shortest paths to the target, each one after all its predecessors.
+    fn ancestors(&self, target: T) -> Vec<T> {
+        let mut order = vec![];
+        let mut seen = HashSet::from([target]);
+        let mut stack = vec![(target, 0)];
+        while let Some((state, next)) = stack.last_mut() {
+            match self.predecessors(*state).get(*next) {
+                Some(&prev) => {
+                    *next += 1;
+                    if seen.insert(prev) {
+                        stack.push((prev, 0));
+                    }
+                }
+                None => {
+                    order.push(*state);
+                    stack.pop();
+                }
+            }
+        }
+        order
+    }
+}
+
+/// Like [`dijkstra`], but record every optimal predecessor of each state instead of just
+/// one, so that all shortest paths can be counted or listed.
+///
+/// Steps costing 0 must not form cycles.
+pub fn dijkstra_all<T, I>(start: T, mut neighbors: impl FnMut(T) -> I) -> ShortestPathDag<T>
+where
+    T: Hash + Eq + Copy,
+    I: IntoIterator<Item = (T, usize)>,
+{
+    let mut dag = ShortestPathDag {
+        start,
+        dist: HashMap::from([(start, 0)]),
+        prev: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::from([MinCost(0, start)]);
+    while let Some(MinCost(dist, state)) = queue.pop() {
+        if dist > dag.dist[&state] {
+            continue;
+        }
+        for (next, cost) in neighbors(state) {
+            let next_dist = dist + cost;
+            match dag.dist.get(&next) {
+                Some(&d) if next_dist > d || next == start => {}
+                Some(&d) if next_dist == d => dag.prev.entry(next).or_default().push(state),
+                _ => {
+                    dag.dist.insert(next, next_dist);
+                    dag.prev.insert(next, vec![state]);
+                    queue.push(MinCost(next_dist, next));
+                }
+            }
+        }
+    }
+    dag
+}
+
+/// Find a shortest path from `start` to a state satisfying `is_goal`, returning its cost
+/// and the states along it.
+///
+/// The heuristic estimates the remaining cost from a state to the goal. It must never
+/// overestimate, or the path found may not be the shortest.
+pub fn astar<T, I>(
+    start: T,
+    mut neighbors: impl FnMut(T) -> I,
+    mut heuristic: impl FnMut(T) -> usize,
+    is_goal: impl Fn(T) -> bool,
+) -> Option<(usize, Vec<T>)>
+where
+    T: Hash + Eq + Copy,
+    I: IntoIterator<Item = (T, usize)>,
+{
+    let mut paths = ShortestPaths {
+        start,
+        dist: HashMap::from([(start, 0)]),
+        prev: HashMap::new(),
+    };
+    let mut queue = BinaryHeap::from([MinCost(heuristic(start), start)]);
+    while let Some(MinCost(estimate, state)) = queue.pop() {
+        let dist = paths.dist[&state];
+        if estimate > dist + heuristic(state) {
+            continue;
+        }
+        if is_goal(state) {
+            return Some((dist, paths.path_to(state).unwrap()));
+        }
+        for (next, cost) in neighbors(state) {
+            let next_dist = dist + cost;
+            if paths.dist.get(&next).is_none_or(|&d| next_dist < d) {
+                paths.dist.insert(next, next_dist);
+                paths.prev.insert(next, state);
+                queue.push(MinCost(next_dist + heuristic(next), next));
+            }
+        }
+    }
+    None
+}
+
+/// Find the shortest paths from `start` to every reachable state, when every step costs
+/// either 0 or 1. Faster than [`dijkstra`] for such graphs.
+///
+/// Panics if a step costs more than 1.
+pub fn bfs_01<T, I>(start: T, mut neighbors: impl FnMut(T) -> I) -> ShortestPaths<T>
+where
+    T: Hash + Eq + Copy,
+    I: IntoIterator<Item = (T, usize)>,
+{
+    let mut paths = ShortestPaths {
+        start,
+        dist: HashMap::from([(start, 0)]),
+        prev: HashMap::new(),
+    };
+    let mut queue = VecDeque::from([(0, start)]);
+    while let Some((dist, state)) = queue.pop_front() {
+        if dist > paths.dist[&state] {
+            continue;
+        }
+        for (next, cost) in neighbors(state) {
+            assert!(cost <= 1, "0-1 BFS step costs must be 0 or 1");
+            let next_dist = dist + cost;
+            if paths.dist.get(&next).is_none_or(|&d| next_dist < d) {
+                paths.dist.insert(next, next_dist);
+                paths.prev.insert(next, state);
+                if cost == 0 {
+                    queue.push_front((next_dist, next));
+                } else {
+                    queue.push_back((next_dist, next));
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// Orders states by cost, lowest first, so that `BinaryHeap` pops the cheapest one.
+struct MinCost<T>(usize, T);
+
+impl<T> PartialEq for MinCost<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for MinCost<T> {}
+
+impl<T> PartialOrd for MinCost<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for MinCost<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}